@@ -0,0 +1,71 @@
+/// Generates the solution registry: with the `registry` feature, every `src/bin/YYYY-DD.rs` is compiled into the library
+/// as a module as well. Without it, the registry is empty and the library does not depend on any solution.
+use std::{env, fs, path::Path};
+
+fn main() {
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+
+    if env::var_os("CARGO_FEATURE_REGISTRY").is_none() {
+        println!("cargo:rerun-if-changed=build.rs");
+        fs::write(out_path, generate(&[])).unwrap();
+        return;
+    }
+
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let bin_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bin");
+
+    let mut puzzles: Vec<(u16, u8, String)> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    let stem = path.file_stem()?.to_str()?;
+                    let (year, day): (u16, u8) = match stem.split_once('-')? {
+                        (year, day) if year.len() == 4 && day.len() == 2 => {
+                            (year.parse().ok()?, day.parse().ok()?)
+                        }
+                        _ => return None,
+                    };
+                    let is_solution = (1..=25).contains(&day) && path.extension()? == "rs";
+                    let path = path.to_str()?.to_string();
+                    is_solution.then_some((year, day, path))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    puzzles.sort_unstable();
+    fs::write(out_path, generate(&puzzles)).unwrap();
+}
+
+fn generate(puzzles: &[(u16, u8, String)]) -> String {
+    let modules: String = puzzles
+        .iter()
+        .map(|(year, day, path)| {
+            format!(
+                "    #[cfg(not(test))]\n    #[allow(dead_code, unused_imports)]\n    #[path = {path:?}]\n    mod year_{year}_day_{day:02};\n"
+            )
+        })
+        .collect();
+
+    let solutions: String = puzzles
+        .iter()
+        .map(|(year, day, _)| format!("        year_{year}_day_{day:02}::SOLUTION,\n"))
+        .collect();
+
+    // solutions are left out of library tests, their own tests already run as part of their binaries.
+    format!(
+        "mod generated {{
+{modules}
+    #[cfg(not(test))]
+    pub static SOLUTIONS: &[crate::template::registry::Solution] = &[
+{solutions}    ];
+
+    #[cfg(test)]
+    pub static SOLUTIONS: &[crate::template::registry::Solution] = &[];
+}}
+"
+    )
+}
@@ -0,0 +1,207 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The answer to a part of a puzzle, either an integer of any size or text.
+///
+/// Answers are classified by their displayed form, so a solution may return any type that implements [`Display`].
+/// Integers are stored without loss, even if they don't fit into an [`i128`].
+///
+/// ```
+/// # use advent_of_code::Answer;
+/// assert_eq!(Answer::from("-42"), Answer::Integer(-42));
+/// assert_eq!(Answer::from("ABCDEF"), Answer::Text("ABCDEF".into()));
+/// assert_eq!(Answer::from_result(&u128::MAX).to_string(), u128::MAX.to_string())
+/// ```
+///
+/// # Serialization
+/// Integers that fit into an [`i64`] are serialized as numbers, other answers as strings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// An integer that fits into an [`i128`].
+    Integer(i128),
+    /// An integer that does not fit into an [`i128`], kept as its decimal digits.
+    BigInteger(String),
+    /// Any other answer, e.g. a code of letters.
+    Text(String),
+}
+
+impl Answer {
+    /// Creates the answer of a solution result from its displayed form.
+    pub fn from_result(result: &impl Display) -> Self {
+        Self::from(result.to_string().as_str())
+    }
+
+    /// Compares two integer answers by value, returns [`None`] if either of them is text.
+    pub fn cmp_integer(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Integer(a), Self::Integer(b)) => Some(a.cmp(b)),
+            (Self::Text(_), _) | (_, Self::Text(_)) => None,
+            _ => Some(cmp_decimal(&self.to_string(), &other.to_string())),
+        }
+    }
+}
+
+/// Compares two integer literals in canonical form by value.
+fn cmp_decimal(a: &str, b: &str) -> Ordering {
+    match (a.strip_prefix('-'), b.strip_prefix('-')) {
+        (None, None) => a.len().cmp(&b.len()).then_with(|| a.cmp(b)),
+        (Some(a), Some(b)) => cmp_decimal(b, a),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+    }
+}
+
+/// Whether `s` is an integer without sign (other than `-`) or leading zeros, i.e. it displays the same after parsing.
+fn is_integer_literal(s: &str) -> bool {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+
+    !digits.is_empty()
+        && digits.bytes().all(|x| x.is_ascii_digit())
+        && (!digits.starts_with('0') || s == "0")
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        let s = s.trim();
+
+        if !is_integer_literal(s) {
+            return Self::Text(s.into());
+        }
+
+        match s.parse() {
+            Ok(x) => Self::Integer(x),
+            Err(_) => Self::BigInteger(s.into()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(x) => write!(f, "{x}"),
+            Self::BigInteger(x) | Self::Text(x) => f.write_str(x),
+        }
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Integer(x) => match i64::try_from(*x) {
+                Ok(x) => serializer.serialize_i64(x),
+                Err(_) => serializer.collect_str(x),
+            },
+            Self::BigInteger(x) | Self::Text(x) => serializer.serialize_str(x),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl Visitor<'_> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an integer or a string")
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Answer, E> {
+                Ok(Answer::Integer(v.into()))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Answer, E> {
+                Ok(Answer::Integer(v.into()))
+            }
+
+            fn visit_i128<E: de::Error>(self, v: i128) -> Result<Answer, E> {
+                Ok(Answer::Integer(v))
+            }
+
+            fn visit_u128<E: de::Error>(self, v: u128) -> Result<Answer, E> {
+                Ok(Answer::from(v.to_string()))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Answer, E> {
+                Ok(Answer::from(v))
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::Answer;
+
+    #[test]
+    fn classifies_answers() {
+        assert_eq!(Answer::from(" 0\n"), Answer::Integer(0));
+        assert_eq!(Answer::from_result(&i128::MIN), Answer::Integer(i128::MIN));
+        assert_eq!(
+            Answer::from_result(&u128::MAX),
+            Answer::BigInteger(u128::MAX.to_string())
+        );
+        assert_eq!(
+            Answer::from("123456789012345678901234567890123456789012"),
+            Answer::BigInteger("123456789012345678901234567890123456789012".into())
+        );
+
+        for text in ["007", "-0", "+1", "1.5", "1e3", "-", "", "ABC"] {
+            assert_eq!(Answer::from(text), Answer::Text(text.into()));
+        }
+    }
+
+    #[test]
+    fn compares_integers() {
+        let big = Answer::from("123456789012345678901234567890123456789012");
+        let negative_big = Answer::from("-123456789012345678901234567890123456789012");
+
+        assert_eq!(
+            Answer::Integer(2).cmp_integer(&Answer::Integer(10)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            big.cmp_integer(&Answer::Integer(i128::MAX)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            negative_big.cmp_integer(&Answer::Integer(-1)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(negative_big.cmp_integer(&big), Some(Ordering::Less));
+        assert_eq!(big.cmp_integer(&big), Some(Ordering::Equal));
+        assert_eq!(Answer::from("ABC").cmp_integer(&Answer::Integer(1)), None);
+    }
+
+    #[test]
+    fn serializes_answers() {
+        let answers = [
+            Answer::Integer(42),
+            Answer::Integer(i128::MAX),
+            Answer::Text("ABC".into()),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, format!("[42,\"{}\",\"ABC\"]", i128::MAX));
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+
+        // answers recorded as strings are classified.
+        assert_eq!(
+            serde_json::from_str::<Answer>("\"42\"").unwrap(),
+            Answer::Integer(42)
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use regex::Regex;
 use std::cmp;
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2023, 2);
 
 #[derive(PartialEq, Debug)]
 enum CubeColor {
@@ -232,13 +232,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(8));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(2286));
     }
 }
@@ -1,6 +1,6 @@
 use regex::Regex;
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(2023, 3);
 
 fn number_digits(n: u32) -> u32 {
     n.checked_ilog10().unwrap_or(0) + 1
@@ -190,13 +190,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(4361));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(467835));
     }
 }
@@ -55,7 +55,7 @@ impl SeedRange {
     }
 }
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2023, 5);
 
 struct SeedMap {
     destination_start: u64,
@@ -356,19 +356,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(35));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(46));
     }
 
     #[test]
     fn test_part_two_final() {
-        let result = part_two(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_two(&advent_of_code::template::read_file("inputs", PUZZLE));
         assert!(result.is_some());
 
         if let Some(result_value) = result {
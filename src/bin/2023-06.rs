@@ -1,4 +1,4 @@
-advent_of_code::solution!(6);
+advent_of_code::solution!(2023, 6);
 
 #[derive(Clone, Copy)]
 struct BoatRace {
@@ -106,26 +106,27 @@ mod tests {
 
     #[test]
     fn test_part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(288));
     }
 
     #[test]
     fn test_part_one_actual() {
-        let result = part_one(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_one(&advent_of_code::template::read_file("inputs", PUZZLE));
         assert!(result.is_some());
         println!("{}", result.unwrap());
     }
 
     #[test]
     fn test_part_two() {
-        let result: Option<u64> = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result: Option<u64> =
+            part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(71503));
     }
 
     #[test]
     fn test_part_two_actual() {
-        let result = part_two(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_two(&advent_of_code::template::read_file("inputs", PUZZLE));
         assert!(result.is_some());
         println!("{}", result.unwrap());
     }
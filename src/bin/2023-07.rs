@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use itertools::Itertools;
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2023, 7);
 
 fn assert_card_hand_len(cards_len: usize) {
     if cards_len < 4 || cards_len > 5 {
@@ -200,13 +200,13 @@ mod tests {
 
     #[test]
     fn test_part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(6440));
     }
 
     #[test]
     fn test_part_one_actual() {
-        let result = part_one(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_one(&advent_of_code::template::read_file("inputs", PUZZLE));
         assert!(result.is_some());
         assert_eq!(result, Some(250254244));
         println!("{}", result.unwrap());
@@ -214,13 +214,13 @@ mod tests {
 
     #[test]
     fn test_part_two_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(5905));
     }
 
     #[test]
     fn test_part_two_actual() {
-        let result = part_two(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_two(&advent_of_code::template::read_file("inputs", PUZZLE));
         assert!(result.is_some());
         println!("{}", result.unwrap());
     }
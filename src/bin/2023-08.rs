@@ -1,7 +1,7 @@
 use num::integer::lcm;
 use std::collections::HashMap;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2023, 8);
 
 #[derive(PartialEq, Eq, Hash, Copy, Clone)]
 enum NavigationDirection {
@@ -200,29 +200,30 @@ pub fn part_two(input: &str) -> Option<u64> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::{template::read_example, Part};
 
     #[test]
     fn test_part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&read_example(PUZZLE, Part::One, None));
         assert_eq!(result, Some(6));
     }
 
     #[test]
     fn run_part_one_actual() {
-        let result = part_one(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_one(&advent_of_code::template::read_file("inputs", PUZZLE));
         assert!(result.is_some());
         println!("{}", result.unwrap());
     }
 
     #[test]
     fn test_part_two_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&read_example(PUZZLE, Part::Two, None));
         assert_eq!(result, Some(6));
     }
 
     #[test]
     fn run_part_two_actual() {
-        let result = part_two(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_two(&advent_of_code::template::read_file("inputs", PUZZLE));
         assert!(result.is_some());
         println!("{}", result.unwrap());
     }
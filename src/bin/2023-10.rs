@@ -2,7 +2,7 @@ use std::thread::current;
 
 use itertools::Itertools;
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(2023, 10);
 
 #[derive(PartialEq, Copy, Clone)]
 enum Direction {
@@ -220,26 +220,26 @@ mod tests {
 
     #[test]
     fn test_part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(4));
     }
 
     #[test]
     fn run_part_one_actual() {
-        let result = part_one(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_one(&advent_of_code::template::read_file("inputs", PUZZLE));
         assert!(result.is_some());
         println!("{}", result.unwrap());
     }
 
     #[test]
     fn test_part_two_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 
     #[test]
     fn run_part_two_actual() {
-        let result = part_two(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_two(&advent_of_code::template::read_file("inputs", PUZZLE));
         assert!(result.is_some());
         println!("{}", result.unwrap());
     }
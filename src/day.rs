@@ -1,6 +1,9 @@
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
 ///
@@ -12,7 +15,8 @@ use std::str::FromStr;
 /// let day = Day::new(8).unwrap();
 /// assert_eq!(day.to_string(), "08")
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
 pub struct Day(u8);
 
 impl Day {
@@ -35,6 +39,32 @@ impl Day {
     pub fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// Returns the instant the puzzle of this day unlocks in the event of `year`,
+    /// i.e. midnight US Eastern Standard Time (UTC-5) on December `day`.
+    pub fn unlock_time(self, year: u16) -> SystemTime {
+        let secs = days_from_civil(year, 12, self.0) * 86_400 + 5 * 3600;
+        let offset = Duration::from_secs(secs.unsigned_abs());
+
+        // instants the platform can't represent are long past, the epoch unlocks just as well.
+        if secs < 0 {
+            UNIX_EPOCH.checked_sub(offset).unwrap_or(UNIX_EPOCH)
+        } else {
+            UNIX_EPOCH.checked_add(offset).unwrap_or(UNIX_EPOCH)
+        }
+    }
+}
+
+/// Converts a date in the proleptic gregorian calendar to days since the unix epoch.
+fn days_from_civil(year: u16, month: u8, day: u8) -> i64 {
+    // see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = i64::from(year) - i64::from(month <= 2);
+    let month = i64::from(month);
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * if month > 2 { month - 3 } else { month + 9 } + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
 }
 
 impl Display for Day {
@@ -55,6 +85,20 @@ impl PartialOrd<u8> for Day {
     }
 }
 
+impl TryFrom<u8> for Day {
+    type Error = DayFromStrError;
+
+    fn try_from(day: u8) -> Result<Self, Self::Error> {
+        Self::new(day).ok_or(DayFromStrError)
+    }
+}
+
+impl From<Day> for u8 {
+    fn from(day: Day) -> Self {
+        day.0
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl FromStr for Day {
@@ -80,6 +124,64 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
+/// A puzzle of an Advent of Code event, identified by the year of the event and its day.
+///
+/// # Display
+/// This value displays as the year followed by the two digit day, which is also the name of its solution binary.
+///
+/// ```
+/// # use advent_of_code::{Day, Puzzle};
+/// let puzzle = Puzzle::new(2023, Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2023-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: Day,
+}
+
+impl Puzzle {
+    pub const fn new(year: u16, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Returns the instant this puzzle unlocks, see [`Day::unlock_time`].
+    pub fn unlock_time(self) -> SystemTime {
+        self.day.unlock_time(self.year)
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/// The year of the first Advent of Code event.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Parses the year of an event, i.e. an integer not before [`FIRST_YEAR`].
+pub fn parse_year(s: &str) -> Result<u16, YearFromStrError> {
+    match s.parse() {
+        Ok(year) if year >= FIRST_YEAR => Ok(year),
+        _ => Err(YearFromStrError),
+    }
+}
+
+/// An error which can be returned when parsing the year of an event.
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting the year of an event, {FIRST_YEAR} or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// An iterator that yields every day of advent from the 1st to the 25th.
 pub fn all_days() -> AllDays {
     AllDays::new()
@@ -114,6 +216,158 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// A set of days of advent, e.g. to run only the days you are working on.
+///
+/// # Syntax
+/// A comma-separated list of days (`8`), ranges (`1-5`) and open-ended ranges (`12..`).
+///
+/// ```
+/// # use advent_of_code::{Day, DaySet};
+/// let days: DaySet = "1-3,8,24..".parse().unwrap();
+/// assert_eq!(days.len(), 6);
+/// assert!(days.contains(Day::new(24).unwrap()));
+/// assert_eq!(days.to_string(), "1-3,8,24-25")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DaySet(u32);
+
+impl DaySet {
+    /// Returns the set of every day of advent.
+    pub fn all() -> Self {
+        all_days().collect()
+    }
+
+    pub fn insert(&mut self, day: Day) {
+        self.0 |= 1 << day.0;
+    }
+
+    pub fn contains(self, day: Day) -> bool {
+        self.0 & (1 << day.0) != 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the days of this set in ascending order.
+    pub fn iter(self) -> impl Iterator<Item = Day> {
+        all_days().filter(move |x| self.contains(*x))
+    }
+}
+
+impl From<Day> for DaySet {
+    fn from(day: Day) -> Self {
+        let mut days = Self::default();
+        days.insert(day);
+        days
+    }
+}
+
+impl FromIterator<Day> for DaySet {
+    fn from_iter<T: IntoIterator<Item = Day>>(iter: T) -> Self {
+        let mut days = Self::default();
+        iter.into_iter().for_each(|x| days.insert(x));
+        days
+    }
+}
+
+impl Display for DaySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ranges: Vec<(u8, u8)> = vec![];
+
+        for day in self.iter().map(Day::into_inner) {
+            match ranges.last_mut() {
+                Some((_, end)) if *end + 1 == day => *end = day,
+                _ => ranges.push((day, day)),
+            }
+        }
+
+        let ranges: Vec<String> = ranges
+            .into_iter()
+            .map(|(start, end)| {
+                if start == end {
+                    start.to_string()
+                } else {
+                    format!("{start}-{end}")
+                }
+            })
+            .collect();
+
+        f.write_str(&ranges.join(","))
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |x: &str| {
+            x.trim()
+                .parse::<Day>()
+                .map_err(|_| DaySetFromStrError::InvalidDay(x.trim().into()))
+        };
+
+        let mut days = Self::default();
+
+        for item in s.split(',').map(str::trim) {
+            if item.is_empty() {
+                return Err(DaySetFromStrError::Empty);
+            }
+
+            let (start, end) = if let Some(start) = item.strip_suffix("..") {
+                (parse_day(start)?, Day(25))
+            } else if let Some((start, end)) = item.split_once('-') {
+                (parse_day(start)?, parse_day(end)?)
+            } else {
+                let day = parse_day(item)?;
+                (day, day)
+            };
+
+            if start > end {
+                return Err(DaySetFromStrError::ReversedRange(start, end));
+            }
+
+            (start.0..=end.0).for_each(|x| days.insert(Day(x)));
+        }
+
+        Ok(days)
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum DaySetFromStrError {
+    Empty,
+    InvalidDay(String),
+    ReversedRange(Day, Day),
+}
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => f.write_str(
+                "expecting a comma-separated list of days (8), ranges (1-5) and open-ended ranges (12..)",
+            ),
+            Self::InvalidDay(x) => {
+                write!(f, "invalid day `{x}`, expecting a day number between 1 and 25")
+            }
+            Self::ReversedRange(start, end) => write!(
+                f,
+                "invalid range `{}-{}`, the first day must not be after the last",
+                start.0, end.0
+            ),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
@@ -134,7 +388,71 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{all_days, parse_year, Day, DaySet, DaySetFromStrError, Puzzle};
+
+    #[test]
+    fn unlock_time() {
+        // 2023-12-01T05:00:00Z
+        assert_eq!(
+            Day(1).unlock_time(2023),
+            UNIX_EPOCH + Duration::from_secs(1_701_406_800)
+        );
+        // 2024-12-25T05:00:00Z
+        assert_eq!(
+            Day(25).unlock_time(2024),
+            UNIX_EPOCH + Duration::from_secs(1_735_102_800)
+        );
+        // years before the epoch don't overflow.
+        assert!(Day(1).unlock_time(23) < Day(1).unlock_time(1970));
+        assert!(Day(1).unlock_time(0) < UNIX_EPOCH);
+    }
+
+    #[test]
+    fn puzzle_identity() {
+        let puzzle = Puzzle::new(2022, Day(3));
+        assert_eq!(puzzle.to_string(), "2022-03");
+        assert_eq!(puzzle.unlock_time(), Day(3).unlock_time(2022));
+        assert!(puzzle < Puzzle::new(2023, Day(1)));
+    }
+
+    #[test]
+    fn parses_years() {
+        assert_eq!(parse_year("2015").unwrap(), 2015);
+        assert!(parse_year("2014").is_err());
+        assert!(parse_year("23").is_err());
+        assert!(parse_year("x").is_err());
+    }
+
+    #[test]
+    fn parses_day_sets() {
+        let days: DaySet = "1-3, 8,24..".parse().unwrap();
+        assert_eq!(
+            days.iter().collect::<Vec<_>>(),
+            [Day(1), Day(2), Day(3), Day(8), Day(24), Day(25)]
+        );
+        assert_eq!(days.to_string(), "1-3,8,24-25");
+
+        assert_eq!("7".parse::<DaySet>().unwrap(), DaySet::from(Day(7)));
+        assert_eq!("1..".parse::<DaySet>().unwrap(), DaySet::all());
+        assert_eq!("3-3,1-5".parse::<DaySet>().unwrap().len(), 5);
+    }
+
+    #[test]
+    fn rejects_invalid_day_sets() {
+        let parse = |s: &str| s.parse::<DaySet>().unwrap_err();
+
+        assert_eq!(parse(""), DaySetFromStrError::Empty);
+        assert_eq!(parse("1,,2"), DaySetFromStrError::Empty);
+        assert_eq!(parse("26"), DaySetFromStrError::InvalidDay("26".into()));
+        assert_eq!(parse("1-x"), DaySetFromStrError::InvalidDay("x".into()));
+        assert_eq!(parse("0.."), DaySetFromStrError::InvalidDay("0".into()));
+        assert_eq!(
+            parse("5-3"),
+            DaySetFromStrError::ReversedRange(Day(5), Day(3))
+        );
+    }
 
     #[test]
     fn all_days_iterator() {
@@ -1,4 +1,11 @@
+// lets solutions compiled into the registry refer to the library as `advent_of_code`, same as the solution binaries.
+extern crate self as advent_of_code;
+
+mod answer;
 mod day;
+mod part;
 pub mod template;
 
+pub use answer::*;
 pub use day::*;
+pub use part::*;
@@ -1,55 +1,174 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
-use args::{parse, AppArguments};
+use advent_of_code::template::backend::{self, AocBackend};
+use advent_of_code::template::commands::{
+    all, compare, download, examples, harvest, login, logout, read, scaffold, solve, whoami,
+};
+use advent_of_code::Puzzle;
+use args::{parse, AppArguments, Arguments};
 
 mod args {
     use std::process;
 
-    use advent_of_code::Day;
+    use std::time::Duration;
+
+    use advent_of_code::{
+        parse_year,
+        template::{
+            aoc_client,
+            runner::{BenchConfig, OutputFormat, RunnerConfig},
+        },
+        Day, DaySet,
+    };
+
+    /// The parsed command, along with the year of the event it applies to.
+    pub struct Arguments {
+        pub year: u16,
+        pub command: AppArguments,
+    }
 
     pub enum AppArguments {
         Download {
             day: Day,
+            wait: bool,
         },
+        Backfill {
+            days: Vec<Day>,
+            delay: Duration,
+        },
+        Examples {
+            day: Day,
+            overwrite: bool,
+        },
+        Harvest,
+        Login,
+        Logout,
         Read {
             day: Day,
         },
         Scaffold {
-            day: Day,
+            days: DaySet,
         },
         Solve {
             day: Day,
             release: bool,
-            time: bool,
-            submit: Option<u8>,
+            config: RunnerConfig,
         },
         All {
+            days: DaySet,
             release: bool,
             time: bool,
+            format: OutputFormat,
+            bench: BenchConfig,
+            compare: Option<f64>,
+            jobs: usize,
         },
+        Compare {
+            days: DaySet,
+            rev: String,
+            release: bool,
+            bench: BenchConfig,
+        },
+        Whoami,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    pub fn parse() -> Result<Arguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
-            },
-            Some("download") => AppArguments::Download {
+        let subcommand = args.subcommand()?;
+        let year = match args.opt_value_from_fn("--year", parse_year)? {
+            Some(year) => year,
+            None => aoc_client::event_year().map_err(|e| format!("invalid AOC_YEAR: {e}"))?,
+        };
+
+        let app_args = match subcommand.as_deref() {
+            Some("all") => {
+                let release = args.contains("--release");
+                let time = args.contains("--time");
+                let threshold = args.opt_value_from_str("--threshold")?.unwrap_or(10.0);
+                let compare = args.contains("--compare").then_some(threshold);
+
+                if compare.is_some() && !(release && time) {
+                    return Err("--compare requires --release and --time".into());
+                }
+
+                let against: Option<String> = args.opt_value_from_str("--against")?;
+                let jobs: Option<usize> = args.opt_value_from_str("--jobs")?;
+                let format: Option<OutputFormat> = args.opt_value_from_str("--format")?;
+                let bench = BenchConfig::parse(&mut args)?;
+                let days = args.opt_free_from_str()?.unwrap_or_else(DaySet::all);
+
+                if let Some(rev) = against {
+                    if compare.is_some() {
+                        return Err("--against can not be combined with --compare".into());
+                    }
+
+                    if jobs.is_some() || format.is_some() {
+                        return Err("--against can not be combined with --jobs or --format".into());
+                    }
+
+                    AppArguments::Compare {
+                        days,
+                        rev,
+                        release,
+                        bench,
+                    }
+                } else {
+                    let jobs = jobs.unwrap_or(1);
+                    if jobs == 0 {
+                        return Err("--jobs must be at least 1".into());
+                    }
+
+                    AppArguments::All {
+                        days,
+                        release,
+                        time,
+                        format: format.unwrap_or_default(),
+                        bench,
+                        compare,
+                        jobs,
+                    }
+                }
+            }
+            Some("download") => {
+                let all = args.contains("--all");
+                let wait = args.contains("--wait");
+                let delay =
+                    Duration::from_millis(args.opt_value_from_str("--delay-ms")?.unwrap_or(1000));
+
+                let days: DaySet = if all {
+                    DaySet::all()
+                } else {
+                    args.free_from_str()?
+                };
+
+                match days.iter().next() {
+                    Some(day) if days.len() == 1 && !all => AppArguments::Download { day, wait },
+                    _ if wait => {
+                        return Err("--wait can only be used to download a single day".into());
+                    }
+                    _ => AppArguments::Backfill {
+                        days: days.iter().collect(),
+                        delay,
+                    },
+                }
+            }
+            Some("examples") => AppArguments::Examples {
                 day: args.free_from_str()?,
+                overwrite: args.contains("--overwrite"),
             },
+            Some("harvest") => AppArguments::Harvest,
+            Some("login") => AppArguments::Login,
+            Some("logout") => AppArguments::Logout,
+            Some("whoami") => AppArguments::Whoami,
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                days: args.free_from_str()?,
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+                config: RunnerConfig::parse(&mut args)?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -66,27 +185,64 @@ mod args {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok(Arguments {
+            year,
+            command: app_args,
+        })
     }
 }
 
+fn get_backend() -> Box<dyn AocBackend> {
+    backend::from_env().unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    })
+}
+
 fn main() {
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+        Ok(Arguments { year, command }) => match command {
+            AppArguments::All {
+                days,
+                release,
+                time,
+                format,
+                bench,
+                compare,
+                jobs,
+            } => all::handle(year, days, release, time, format, bench, compare, jobs),
+            AppArguments::Compare {
+                days,
+                rev,
+                release,
+                bench,
+            } => compare::handle(year, days, &rev, release, bench),
+            AppArguments::Download { day, wait } => {
+                download::handle(get_backend().as_ref(), Puzzle::new(year, day), wait);
+            }
+            AppArguments::Backfill { days, delay } => {
+                download::handle_backfill(get_backend().as_ref(), year, &days, delay);
+            }
+            AppArguments::Examples { day, overwrite } => {
+                examples::handle(Puzzle::new(year, day), overwrite);
+            }
+            AppArguments::Harvest => harvest::handle(year),
+            AppArguments::Login => login::handle(year),
+            AppArguments::Logout => logout::handle(),
+            AppArguments::Whoami => whoami::handle(year),
+            AppArguments::Read { day } => {
+                read::handle(get_backend().as_ref(), Puzzle::new(year, day));
+            }
+            AppArguments::Scaffold { days } => scaffold::handle(year, days),
             AppArguments::Solve {
                 day,
                 release,
-                time,
-                submit,
-            } => solve::handle(day, release, time, submit),
+                config,
+            } => solve::handle(Puzzle::new(year, day), release, &config),
         },
     };
 }
@@ -0,0 +1,201 @@
+/// Module that stores confirmed answers in `data/YYYY/answers/DD.toml` and verifies solution results against them.
+///
+/// ```toml
+/// part_one = "12345"
+/// part_two = 67890
+/// ```
+use std::{fmt::Display, fs, io, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::get_data_path;
+use crate::{Answer, Part, Puzzle};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(s) => write!(f, "could not parse answers: {s}"),
+            Error::IO(e) => write!(f, "could not access answers: {e}"),
+        }
+    }
+}
+
+/// The confirmed answers for a puzzle. Answers may be written as TOML strings or integers.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<Answer>,
+}
+
+/// The outcome of comparing a result with the confirmed answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    /// There is no confirmed answer for this part.
+    Unknown,
+    Correct,
+    Incorrect {
+        expected: Answer,
+    },
+}
+
+impl Verification {
+    /// `Some(true)` if the answer is correct, `Some(false)` if it's not and [`None`] if it's unknown.
+    pub fn is_correct(&self) -> Option<bool> {
+        match self {
+            Verification::Unknown => None,
+            Verification::Correct => Some(true),
+            Verification::Incorrect { .. } => Some(false),
+        }
+    }
+
+    /// Marker that is displayed next to the result of a part.
+    pub fn marker(&self) -> String {
+        match self {
+            Verification::Unknown => String::new(),
+            Verification::Correct => " ✓".into(),
+            Verification::Incorrect { expected } => format!(" ✗ (expected {expected})"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: Answer) {
+        match part {
+            Part::One => self.part_one = Some(answer),
+            Part::Two => self.part_two = Some(answer),
+        }
+    }
+
+    /// Compares a result with the confirmed answer for a part.
+    /// A missing result counts as incorrect if the answer is known.
+    pub fn verify(&self, part: Part, result: Option<&Answer>) -> Verification {
+        match self.get(part) {
+            None => Verification::Unknown,
+            Some(expected) if Some(expected) == result => Verification::Correct,
+            Some(expected) => Verification::Incorrect {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path(puzzle: Puzzle) -> PathBuf {
+    get_data_path("answers", puzzle, "toml")
+}
+
+fn parse(content: &str) -> Result<Answers, Error> {
+    toml::from_str(content).map_err(|e| Error::Parser(e.to_string()))
+}
+
+/// Reads the confirmed answers for a puzzle. A missing file means that no answers are known.
+pub fn read(puzzle: Puzzle) -> Result<Answers, Error> {
+    match fs::read_to_string(get_path(puzzle)) {
+        Ok(content) => parse(&content),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn write(puzzle: Puzzle, answers: &Answers) -> Result<(), Error> {
+    let content = toml::to_string(answers).map_err(|e| Error::Parser(e.to_string()))?;
+    let path = get_path(puzzle);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, Answers, Verification};
+    use crate::{Answer, Part};
+
+    #[test]
+    fn parses_strings_and_integers() {
+        let answers = parse("part_one = 12345\npart_two = \"ABCDEF\"\n").unwrap();
+        assert_eq!(answers.get(Part::One), Some(&Answer::Integer(12345)));
+        assert_eq!(answers.get(Part::Two), Some(&Answer::Text("ABCDEF".into())));
+    }
+
+    #[test]
+    fn parses_big_integers() {
+        let answers = parse("part_one = \"170141183460469231731687303715884105728\"\n").unwrap();
+        assert_eq!(
+            answers.get(Part::One),
+            Some(&Answer::BigInteger(
+                "170141183460469231731687303715884105728".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn parses_partial_answers() {
+        let answers = parse("part_one = \"42\"\n").unwrap();
+        assert_eq!(answers.get(Part::One), Some(&Answer::Integer(42)));
+        assert_eq!(answers.get(Part::Two), None);
+        assert_eq!(parse("").unwrap(), Answers::default());
+    }
+
+    #[test]
+    fn rejects_invalid_answers() {
+        assert!(parse("part_one = [1, 2]").is_err());
+    }
+
+    #[test]
+    fn verifies_results() {
+        let answers = Answers {
+            part_one: Some(Answer::Integer(42)),
+            part_two: None,
+        };
+        let verify =
+            |part, result: Option<&str>| answers.verify(part, result.map(Answer::from).as_ref());
+
+        assert_eq!(verify(Part::One, Some("42")), Verification::Correct);
+        assert_eq!(
+            verify(Part::One, Some("41")),
+            Verification::Incorrect {
+                expected: Answer::Integer(42)
+            }
+        );
+        assert_eq!(
+            verify(Part::One, None),
+            Verification::Incorrect {
+                expected: Answer::Integer(42)
+            }
+        );
+        assert_eq!(verify(Part::Two, Some("1")), Verification::Unknown);
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let mut answers = Answers::default();
+        answers.set(Part::One, Answer::Text("ABC".into()));
+        answers.set(Part::Two, Answer::Integer(123));
+        let content = toml::to_string(&answers).unwrap();
+        assert_eq!(content, "part_one = \"ABC\"\npart_two = 123\n");
+        assert_eq!(parse(&content).unwrap(), answers);
+    }
+}
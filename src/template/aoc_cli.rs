@@ -1,10 +1,13 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
+    env,
     fmt::Display,
+    fs, process,
     process::{Command, Output, Stdio},
 };
 
-use crate::Day;
+use crate::template::backend::{self, AocBackend};
+use crate::{Answer, Part, Puzzle};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -27,101 +30,184 @@ impl Display for AocCommandError {
     }
 }
 
-pub fn check() -> Result<(), AocCommandError> {
-    Command::new("aoc")
-        .arg("-V")
-        .output()
-        .map_err(|_| AocCommandError::CommandNotFound)?;
-    Ok(())
+/// Backend that calls an installed aoc-cli.
+pub struct AocCli {
+    program: String,
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+impl Default for AocCli {
+    fn default() -> Self {
+        Self::with_program("aoc")
+    }
+}
 
-    let args = build_args(
-        "read",
-        &[
-            "--description-only".into(),
-            "--puzzle-file".into(),
-            puzzle_path,
-        ],
-        day,
-    );
+impl AocCli {
+    /// Creates a backend that calls `program` instead of `aoc`.
+    pub fn with_program(program: &str) -> Self {
+        Self {
+            program: program.to_string(),
+        }
+    }
 
-    call_aoc_cli(&args)
-}
+    pub fn check(&self) -> Result<(), AocCommandError> {
+        Command::new(&self.program)
+            .arg("-V")
+            .output()
+            .map_err(|_| AocCommandError::CommandNotFound)?;
+        Ok(())
+    }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
-
-    let args = build_args(
-        "download",
-        &[
-            "--overwrite".into(),
-            "--input-file".into(),
-            input_path.to_string(),
-            "--puzzle-file".into(),
-            puzzle_path.to_string(),
-        ],
-        day,
-    );
-
-    let output = call_aoc_cli(&args)?;
-    println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-    Ok(output)
-}
+    pub fn input(&self, puzzle: Puzzle) -> Result<String, AocCommandError> {
+        self.download_to_file(puzzle, "--input-only", "--input-file")
+    }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
-    // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
-    args.push(part.to_string());
-    args.push(result.to_string());
-    call_aoc_cli(&args)
-}
+    pub fn puzzle(&self, puzzle: Puzzle) -> Result<String, AocCommandError> {
+        self.download_to_file(puzzle, "--puzzle-only", "--puzzle-file")
+    }
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
-}
+    /// Submits an answer and returns the response printed by aoc-cli.
+    pub fn submit(
+        &self,
+        puzzle: Puzzle,
+        part: Part,
+        result: &Answer,
+    ) -> Result<String, AocCommandError> {
+        // workaround: the argument order is inverted for submit.
+        let mut args = build_args("submit", &[], puzzle);
+        args.push(part.to_string());
+        args.push(result.to_string());
+
+        let output = self.call(&args)?;
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
-}
+    /// aoc-cli only writes downloads to files, so download to a temporary file and read it back.
+    fn download_to_file(
+        &self,
+        puzzle: Puzzle,
+        only_flag: &str,
+        file_flag: &str,
+    ) -> Result<String, AocCommandError> {
+        let path = env::temp_dir().join(format!("aoc-cli-{}-{puzzle}", process::id()));
+
+        let args = build_args(
+            "download",
+            &[
+                "--overwrite".into(),
+                only_flag.into(),
+                file_flag.into(),
+                path.to_string_lossy().to_string(),
+            ],
+            puzzle,
+        );
+
+        let result = self
+            .call(&args)
+            .and_then(|_| fs::read_to_string(&path).map_err(|_| AocCommandError::IoError));
+        let _ = fs::remove_file(&path);
+        result
+    }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
+    fn call(&self, args: &[String]) -> Result<Output, AocCommandError> {
+        // capture stdout so the output can be processed.
+        let output = Command::new(&self.program)
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+        if output.status.success() {
+            Ok(output)
+        } else {
+            Err(AocCommandError::BadExitStatus(output))
+        }
     }
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
-    let mut cmd_args = args.to_vec();
+impl AocBackend for AocCli {
+    fn check(&self) -> Result<(), backend::Error> {
+        Ok(AocCli::check(self)?)
+    }
+
+    fn input(&self, puzzle: Puzzle) -> Result<String, backend::Error> {
+        Ok(AocCli::input(self, puzzle)?)
+    }
+
+    fn puzzle(&self, puzzle: Puzzle) -> Result<String, backend::Error> {
+        Ok(AocCli::puzzle(self, puzzle)?)
+    }
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+    fn submit(
+        &self,
+        puzzle: Puzzle,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<String, backend::Error> {
+        Ok(AocCli::submit(self, puzzle, part, answer)?)
     }
+}
+
+fn build_args(command: &str, args: &[String], puzzle: Puzzle) -> Vec<String> {
+    let mut cmd_args = args.to_vec();
 
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        puzzle.year.to_string(),
+        "--day".into(),
+        puzzle.day.to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
 
-fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
-    // println!("Calling >aoc with: {}", args.join(" "));
-    let output = Command::new("aoc")
-        .args(args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .output()
-        .map_err(|_| AocCommandError::CommandNotCallable)?;
-
-    if output.status.success() {
-        Ok(output)
-    } else {
-        Err(AocCommandError::BadExitStatus(output))
+#[cfg(all(feature = "test_lib", unix))]
+mod tests {
+    use std::{env, fs, os::unix::fs::PermissionsExt, path::PathBuf, process};
+
+    use super::AocCli;
+    use crate::template::submissions::Verdict;
+    use crate::{day, Answer, Part, Puzzle};
+
+    /// Writes an executable stub for `aoc` that runs `script`.
+    fn stub(name: &str, script: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-cli-stub-{}-{name}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("aoc");
+        fs::write(&path, format!("#!/bin/sh\n{script}")).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[test]
+    fn parses_submit_verdict() {
+        let path = stub(
+            "submit",
+            "echo \"[INFO  aoc] 🎄 aoc-cli - Advent of Code command-line tool\"\necho \"That's not the right answer; your answer is too low. If you're stuck, make\"\necho \"sure you're using the full input data.\"\n",
+        );
+
+        let response = AocCli::with_program(path.to_str().unwrap())
+            .submit(Puzzle::new(2023, day!(1)), Part::One, &Answer::Integer(42))
+            .unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(Verdict::from_response(&response), Verdict::TooLow);
+    }
+
+    #[test]
+    fn reads_downloaded_input() {
+        // writes the input to the file passed via --input-file.
+        let path = stub(
+            "input",
+            "for arg in \"$@\"; do case $prev in --input-file) echo \"1abc2\" > \"$arg\";; esac; prev=$arg; done\n",
+        );
+
+        let input = AocCli::with_program(path.to_str().unwrap())
+            .input(Puzzle::new(2023, day!(1)))
+            .unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(input, "1abc2\n");
     }
 }
@@ -0,0 +1,537 @@
+/// Built-in client for the Advent of Code website.
+/// Downloads inputs and puzzle descriptions and submits answers using the session cookie of a logged-in user.
+use std::{
+    env,
+    fmt::Display,
+    io,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::backend::{self, AocBackend};
+use crate::template::session::{self, Session};
+use crate::{parse_year, Answer, Part, Puzzle, YearFromStrError};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum Error {
+    /// No session cookie was found in the environment or the session file.
+    MissingSession,
+    /// The server responded with an unexpected status code.
+    Http(u16),
+    /// The server could not be reached.
+    Transport(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "no session cookie found. Run \"cargo aoc-login\" or set ADVENT_OF_CODE_SESSION."
+            ),
+            Error::Http(404) => write!(f, "puzzle not found. Is it unlocked yet?"),
+            Error::Http(400 | 401 | 500) => {
+                write!(
+                    f,
+                    "request was rejected. Is the session cookie still valid?"
+                )
+            }
+            Error::Http(status) => write!(f, "server responded with status {status}."),
+            Error::Transport(e) => write!(f, "could not reach Advent of Code: {e}"),
+            Error::IO(e) => write!(f, "could not write output files to file system: {e}"),
+        }
+    }
+}
+
+/// A client for the Advent of Code website.
+pub struct AocClient {
+    base_url: String,
+    session: Session,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: Session) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent,
+        }
+    }
+
+    /// Creates a client from the environment:
+    ///  1. the base url is read from `AOC_BASE_URL` and defaults to `https://adventofcode.com`.
+    ///  2. the session cookie is read from `ADVENT_OF_CODE_SESSION` or `~/.adventofcode.session`.
+    pub fn from_env() -> Result<Self, Error> {
+        let session = session::load().ok_or(Error::MissingSession)?;
+        Ok(Self::from_env_with(session))
+    }
+
+    /// Creates a client for `session`, reading everything else from the environment like [`AocClient::from_env`].
+    pub fn from_env_with(session: Session) -> Self {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Self::new(&base_url, session)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session.token())
+    }
+
+    fn day_url(&self, puzzle: Puzzle) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn get(&self, url: &str) -> Result<String, Error> {
+        let response = self.agent.get(url).set("Cookie", &self.cookie()).call();
+        into_string(response)
+    }
+
+    /// Downloads the input of a puzzle.
+    pub fn input(&self, puzzle: Puzzle) -> Result<String, Error> {
+        self.get(&format!("{}/input", self.day_url(puzzle)))
+    }
+
+    /// Downloads the description of a puzzle as markdown.
+    pub fn puzzle(&self, puzzle: Puzzle) -> Result<String, Error> {
+        let html = self.get(&self.day_url(puzzle))?;
+        Ok(to_markdown(&html, &self.base_url))
+    }
+
+    /// Submits an answer and returns the response message as markdown.
+    pub fn submit(&self, puzzle: Puzzle, part: Part, answer: &Answer) -> Result<String, Error> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(puzzle)))
+            .set("Cookie", &self.cookie())
+            .send_form(&[
+                ("level", &part.to_string()),
+                ("answer", &answer.to_string()),
+            ]);
+        let html = into_string(response)?;
+        Ok(to_markdown(&html, &self.base_url))
+    }
+
+    /// Returns the name of the logged-in user, or [`None`] if the session is expired.
+    /// Uses the calendar page of the event of `year`, which is cheap to request.
+    pub fn whoami(&self, year: u16) -> Result<Option<String>, Error> {
+        let html = self.get(&format!("{}/{year}", self.base_url))?;
+        Ok(parse_user(&html))
+    }
+}
+
+/// Extracts the user name from the header of a page. The header only contains it for logged-in users.
+fn parse_user(html: &str) -> Option<String> {
+    let start = html.find("<div class=\"user\">")? + "<div class=\"user\">".len();
+    let end = html[start..].find('<').map_or(html.len(), |x| start + x);
+    let user = decode_entities(html[start..end].trim());
+    (!user.is_empty()).then_some(user)
+}
+
+fn into_string(response: Result<ureq::Response, ureq::Error>) -> Result<String, Error> {
+    match response {
+        Ok(response) => response.into_string().map_err(Error::IO),
+        Err(ureq::Error::Status(status, _)) => Err(Error::Http(status)),
+        Err(ureq::Error::Transport(e)) => Err(Error::Transport(e.to_string())),
+    }
+}
+
+/// The year of the event to work on: `AOC_YEAR` if set, the most recent event otherwise.
+/// Fails if `AOC_YEAR` is not the year of an event.
+pub fn event_year() -> Result<u16, YearFromStrError> {
+    match env::var("AOC_YEAR") {
+        Ok(year) => parse_year(&year),
+        Err(_) => Ok(current_event_year()),
+    }
+}
+
+/// The year of the most recent event, i.e. the current year from December on and the previous year before.
+fn current_event_year() -> u16 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |x| x.as_secs());
+    let (year, month) = year_and_month(secs / 86_400);
+    if month == 12 {
+        year
+    } else {
+        year - 1
+    }
+}
+
+/// Converts days since the unix epoch to a (year, month) pair in the proleptic gregorian calendar.
+fn year_and_month(days: u64) -> (u16, u8) {
+    // see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    #[allow(clippy::cast_possible_truncation)]
+    (year as u16, month as u8)
+}
+
+impl AocBackend for AocClient {
+    /// The session cookie is checked when the client is created.
+    fn check(&self) -> Result<(), backend::Error> {
+        Ok(())
+    }
+
+    fn input(&self, puzzle: Puzzle) -> Result<String, backend::Error> {
+        Ok(AocClient::input(self, puzzle)?)
+    }
+
+    fn puzzle(&self, puzzle: Puzzle) -> Result<String, backend::Error> {
+        Ok(AocClient::puzzle(self, puzzle)?)
+    }
+
+    fn submit(
+        &self,
+        puzzle: Puzzle,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<String, backend::Error> {
+        Ok(AocClient::submit(self, puzzle, part, answer)?)
+    }
+}
+
+/// Converts the `<article>` elements of an Advent of Code page to markdown.
+/// Paragraphs reporting an answer (`Your puzzle answer was ...`) are kept as well.
+fn to_markdown(html: &str, base_url: &str) -> String {
+    // move emphasis outside of inline code so it survives the conversion.
+    let html = html
+        .replace("<code><em>", "<em><code>")
+        .replace("</em></code>", "</code></em>");
+
+    let mut sections = vec![];
+    let mut rest = html.as_str();
+
+    loop {
+        let article = rest.find("<article");
+        let answer = rest.find("<p>Your puzzle answer was");
+
+        let (start, end_tag) = match (article, answer) {
+            (Some(a), Some(b)) if b < a => (b, "</p>"),
+            (None, Some(b)) => (b, "</p>"),
+            (Some(a), _) => (a, "</article>"),
+            (None, None) => break,
+        };
+
+        let Some(end) = rest[start..]
+            .find(end_tag)
+            .map(|x| start + x + end_tag.len())
+        else {
+            break;
+        };
+
+        sections.push(convert_section(&rest[start..end], base_url));
+        rest = &rest[end..];
+    }
+
+    let mut markdown = sections.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+fn convert_section(html: &str, base_url: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+    let mut rest = html;
+
+    let blank_line = |out: &mut String| {
+        let trimmed = out.trim_end_matches(' ').len();
+        out.truncate(trimmed);
+        if !out.is_empty() && !out.ends_with("\n\n") {
+            out.push_str(if out.ends_with('\n') { "\n" } else { "\n\n" });
+        }
+    };
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut out, rest, in_pre);
+            break;
+        };
+
+        push_text(&mut out, &rest[..start], in_pre);
+
+        let Some(end) = rest[start..].find('>').map(|x| start + x) else {
+            break;
+        };
+
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+
+        match (name, is_closing) {
+            ("h2", false) => {
+                blank_line(&mut out);
+                out.push_str("## ");
+            }
+            ("p" | "h2" | "ul", true) => out.push_str("\n\n"),
+            ("p" | "ul", false) => blank_line(&mut out),
+            ("li", false) => {
+                if !out.is_empty() && !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("- ");
+            }
+            ("li", true) => out.push('\n'),
+            ("pre", false) => {
+                blank_line(&mut out);
+                out.push_str("```\n");
+                in_pre = true;
+            }
+            ("pre", true) => {
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+                in_pre = false;
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("br", _) => out.push('\n'),
+            ("a", false) => {
+                let href = get_attribute(tag, "href").unwrap_or_default();
+                let href = if href.starts_with('/') {
+                    format!("{base_url}{href}")
+                } else {
+                    href
+                };
+                links.push(href);
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    let mut markdown = out.trim().to_string();
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    markdown
+}
+
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    let text = decode_entities(text);
+    if in_pre {
+        out.push_str(&text);
+    } else {
+        let text = text.replace('\n', " ");
+        // whitespace between block elements is not part of the text.
+        if out.is_empty() || out.ends_with('\n') {
+            out.push_str(text.trim_start());
+        } else {
+            out.push_str(&text);
+        }
+    }
+}
+
+fn get_attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')? + start;
+    Some(decode_entities(&tag[start..end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use super::{parse_user, to_markdown, year_and_month, AocClient, Error};
+    use crate::template::session::Session;
+    use crate::{day, Answer, Part, Puzzle};
+
+    static TOKEN: &str = "53616c7465645f5f0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    fn session() -> Session {
+        Session::parse(TOKEN).unwrap()
+    }
+
+    /// A request received by the mock server.
+    struct Request {
+        head: String,
+        body: String,
+    }
+
+    /// Starts a server on a random local port that answers a single request with `status` and `body`.
+    fn serve_once(status: u16, body: &'static str) -> (String, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+
+            let content_length = head
+                .lines()
+                .find_map(|l| {
+                    l.to_lowercase()
+                        .strip_prefix("content-length:")
+                        .map(|x| x.trim().parse::<usize>().unwrap())
+                })
+                .unwrap_or(0);
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+
+            write!(
+                stream,
+                "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            tx.send(Request {
+                head,
+                body: String::from_utf8(request_body).unwrap(),
+            })
+            .unwrap();
+        });
+
+        (url, rx)
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (url, rx) = serve_once(200, "1abc2\npqr3stu8vwx\n");
+        let client = AocClient::new(&url, session());
+        assert_eq!(
+            client.input(Puzzle::new(2023, day!(1))).unwrap(),
+            "1abc2\npqr3stu8vwx\n"
+        );
+
+        let request = rx.recv().unwrap();
+        assert!(request.head.starts_with("GET /2023/day/1/input HTTP/1.1"));
+        assert!(request.head.contains(&format!("session={TOKEN}")));
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (url, rx) = serve_once(
+            200,
+            "<main><article><p>That's the right answer! <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article></main>",
+        );
+        let client = AocClient::new(&url, session());
+        assert_eq!(
+            client
+                .submit(Puzzle::new(2023, day!(1)), Part::Two, &Answer::from("42\n"))
+                .unwrap(),
+            format!("That's the right answer! [[Return to Day 1]]({url}/2023/day/1)\n")
+        );
+
+        let request = rx.recv().unwrap();
+        assert!(request.head.starts_with("POST /2023/day/1/answer HTTP/1.1"));
+        assert_eq!(request.body, "level=2&answer=42");
+    }
+
+    #[test]
+    fn reports_http_errors() {
+        let (url, _rx) = serve_once(404, "Not Found");
+        let client = AocClient::new(&url, session());
+        assert!(matches!(
+            client.puzzle(Puzzle::new(2023, day!(25))),
+            Err(Error::Http(404))
+        ));
+    }
+
+    #[test]
+    fn converts_puzzle_to_markdown() {
+        let html = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is <em>wrong</em> with
+global snow production.</p>
+<pre><code>1abc2
+a&lt;b
+</code></pre>
+<ul>
+<li>In <code>1abc2</code>, the value is <code><em>12</em></code>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>54953</code>.</p>
+<form method="post"><input type="text" name="answer"/></form>
+</main></body></html>"#;
+
+        assert_eq!(
+            to_markdown(html, "https://adventofcode.com"),
+            "## --- Day 1: Trebuchet?! ---\n\nSomething is *wrong* with global snow production.\n\n```\n1abc2\na<b\n```\n\n- In `1abc2`, the value is *`12`*.\n\nYour puzzle answer was `54953`.\n"
+        );
+    }
+
+    #[test]
+    fn checks_session() {
+        let (url, rx) = serve_once(
+            200,
+            "<header><div class=\"user\">Santa &amp; Co <span class=\"star-count\">50*</span></div></header>",
+        );
+        let client = AocClient::new(&url, session());
+        assert_eq!(client.whoami(2023).unwrap(), Some("Santa & Co".into()));
+        assert!(rx.recv().unwrap().head.starts_with("GET /2023 HTTP/1.1"));
+
+        let html = "<header><div><a href=\"/2023/auth/login\">[Log In]</a></div></header>";
+        assert_eq!(parse_user(html), None);
+    }
+
+    #[test]
+    fn converts_dates() {
+        assert_eq!(year_and_month(0), (1970, 1));
+        // 2023-12-01
+        assert_eq!(year_and_month(19_692), (2023, 12));
+        // 2024-02-29
+        assert_eq!(year_and_month(19_782), (2024, 2));
+    }
+}
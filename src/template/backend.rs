@@ -0,0 +1,123 @@
+/// Abstraction over the ways of talking to Advent of Code.
+/// Commands receive an [`AocBackend`] so they can be tested without network access.
+use std::{env, fmt::Display, io};
+
+use crate::template::{aoc_cli, aoc_client};
+use crate::{Answer, Part, Puzzle};
+
+#[derive(Debug)]
+pub enum Error {
+    AocCli(aoc_cli::AocCommandError),
+    Client(aoc_client::Error),
+    /// The fake backend has no data for the request.
+    Missing(String),
+    IO(io::Error),
+}
+
+impl From<aoc_cli::AocCommandError> for Error {
+    fn from(e: aoc_cli::AocCommandError) -> Self {
+        Error::AocCli(e)
+    }
+}
+
+impl From<aoc_client::Error> for Error {
+    fn from(e: aoc_client::Error) -> Self {
+        Error::Client(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::AocCli(e) => write!(f, "{e}"),
+            Error::Client(e) => write!(f, "{e}"),
+            Error::Missing(s) => write!(f, "no fixture for {s}."),
+            Error::IO(e) => write!(f, "could not access file system: {e}"),
+        }
+    }
+}
+
+/// The operations the template needs from Advent of Code.
+pub trait AocBackend {
+    /// Checks whether the backend is usable, e.g. whether a dependency is installed.
+    fn check(&self) -> Result<(), Error>;
+
+    /// Returns the input of a puzzle.
+    fn input(&self, puzzle: Puzzle) -> Result<String, Error>;
+
+    /// Returns the description of a puzzle as markdown.
+    fn puzzle(&self, puzzle: Puzzle) -> Result<String, Error>;
+
+    /// Submits an answer and returns the response.
+    fn submit(&self, puzzle: Puzzle, part: Part, answer: &Answer) -> Result<String, Error>;
+}
+
+/// Creates the backend selected by `AOC_BACKEND`: `aoc-cli` for an installed aoc-cli, the built-in client otherwise.
+pub fn from_env() -> Result<Box<dyn AocBackend>, Error> {
+    let backend: Box<dyn AocBackend> = match env::var("AOC_BACKEND").as_deref() {
+        Ok("aoc-cli") => Box::new(aoc_cli::AocCli::default()),
+        _ => Box::new(aoc_client::AocClient::from_env()?),
+    };
+    backend.check()?;
+    Ok(backend)
+}
+
+#[cfg(feature = "test_lib")]
+pub use fake::FakeBackend;
+
+#[cfg(feature = "test_lib")]
+mod fake {
+    use std::{
+        cell::RefCell,
+        collections::{HashMap, VecDeque},
+    };
+
+    use super::{AocBackend, Error};
+    use crate::{Answer, Part, Puzzle};
+
+    /// An offline backend that serves fixtures and records submissions.
+    #[derive(Default)]
+    pub struct FakeBackend {
+        pub inputs: HashMap<Puzzle, String>,
+        pub puzzles: HashMap<Puzzle, String>,
+        /// The responses to submissions, in order.
+        pub responses: RefCell<VecDeque<String>>,
+        pub submissions: RefCell<Vec<(Puzzle, Part, Answer)>>,
+    }
+
+    impl AocBackend for FakeBackend {
+        fn check(&self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn input(&self, puzzle: Puzzle) -> Result<String, Error> {
+            self.inputs
+                .get(&puzzle)
+                .cloned()
+                .ok_or_else(|| Error::Missing(format!("input of {puzzle}")))
+        }
+
+        fn puzzle(&self, puzzle: Puzzle) -> Result<String, Error> {
+            self.puzzles
+                .get(&puzzle)
+                .cloned()
+                .ok_or_else(|| Error::Missing(format!("puzzle {puzzle}")))
+        }
+
+        fn submit(&self, puzzle: Puzzle, part: Part, answer: &Answer) -> Result<String, Error> {
+            self.submissions
+                .borrow_mut()
+                .push((puzzle, part, answer.clone()));
+            self.responses
+                .borrow_mut()
+                .pop_front()
+                .ok_or_else(|| Error::Missing(format!("response to submission of {puzzle}")))
+        }
+    }
+}
@@ -0,0 +1,276 @@
+/// Module that keeps a history of benchmark runs and detects regressions between them.
+/// Every run is appended as a single JSON line to the history file of its event, `data/YYYY/benchmarks.jsonl`.
+use std::{
+    fmt::Display,
+    io,
+    path::PathBuf,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::readme_benchmarks::{Timing, Timings};
+use crate::template::runner::nanos;
+use crate::template::{get_data_dir, jsonl};
+use crate::{Day, Part};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<jsonl::Error> for Error {
+    fn from(e: jsonl::Error) -> Self {
+        match e {
+            jsonl::Error::Parser(s) => Error::Parser(s),
+            jsonl::Error::IO(e) => Error::IO(e),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(s) => write!(f, "could not parse benchmark history: {s}"),
+            Error::IO(e) => write!(f, "could not access benchmark history: {e}"),
+        }
+    }
+}
+
+/// The median time of a single solution part in a benchmark run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartTiming {
+    pub day: Day,
+    pub part: Part,
+    #[serde(rename = "median_nanos", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "spread_nanos", with = "nanos::option")]
+    pub spread: Option<Duration>,
+}
+
+/// A single benchmark run, tagged with the git commit it was run against.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub commit: Option<String>,
+    pub dirty: bool,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub timings: Vec<PartTiming>,
+}
+
+impl HistoryEntry {
+    /// Creates an entry for the current time and git commit.
+    pub fn new(timings: &[Timings]) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |x| x.as_secs());
+
+        Self {
+            commit: git_commit(),
+            dirty: git_is_dirty(),
+            timestamp,
+            timings: to_part_timings(timings),
+        }
+    }
+}
+
+/// A solution part that got slower between two benchmark runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: Day,
+    pub part: Part,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Regression {
+    /// The relative slowdown in percent.
+    pub fn change_percent(&self) -> f64 {
+        (self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {} Part {}: {:.1?} → {:.1?} (+{:.1}%)",
+            self.day,
+            self.part,
+            self.before,
+            self.after,
+            self.change_percent()
+        )
+    }
+}
+
+fn to_part_timings(timings: &[Timings]) -> Vec<PartTiming> {
+    let to_part_timing = |day: Day, part: Part, timing: Option<Timing>| {
+        timing.map(|timing| PartTiming {
+            day,
+            part,
+            median: timing.duration,
+            spread: timing.spread,
+        })
+    };
+
+    timings
+        .iter()
+        .flat_map(|x| {
+            [
+                to_part_timing(x.day, Part::One, x.part_1),
+                to_part_timing(x.day, Part::Two, x.part_2),
+            ]
+        })
+        .flatten()
+        .collect()
+}
+
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn git_is_dirty() -> bool {
+    Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty())
+}
+
+/// Compares two runs and returns every part whose median got slower by more than `threshold_percent`.
+/// Parts that are missing from either run are ignored.
+pub fn find_regressions(
+    previous: &HistoryEntry,
+    current: &HistoryEntry,
+    threshold_percent: f64,
+) -> Vec<Regression> {
+    current
+        .timings
+        .iter()
+        .filter_map(|after| {
+            let before = previous
+                .timings
+                .iter()
+                .find(|x| x.day == after.day && x.part == after.part)?;
+
+            let regression = Regression {
+                day: after.day,
+                part: after.part,
+                before: before.median,
+                after: after.median,
+            };
+
+            (regression.before > Duration::ZERO && regression.change_percent() > threshold_percent)
+                .then_some(regression)
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn get_path(year: u16) -> PathBuf {
+    get_data_dir(year).join("benchmarks.jsonl")
+}
+
+/// Returns the most recent run in the history of an event, if there is one.
+pub fn latest(year: u16) -> Result<Option<HistoryEntry>, Error> {
+    Ok(jsonl::read(&get_path(year))?.pop())
+}
+
+pub fn append(year: u16, entry: &HistoryEntry) -> Result<(), Error> {
+    Ok(jsonl::append(&get_path(year), entry)?)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{find_regressions, HistoryEntry, PartTiming};
+    use crate::template::jsonl;
+    use crate::template::readme_benchmarks::{Timing, Timings};
+    use crate::{day, Part};
+
+    fn entry(timings: &[(u8, u8, u64)]) -> HistoryEntry {
+        HistoryEntry {
+            commit: Some("abc1234".into()),
+            dirty: false,
+            timestamp: 1_701_388_800,
+            timings: timings
+                .iter()
+                .map(|&(day, part, median_nanos)| PartTiming {
+                    day: day.try_into().unwrap(),
+                    part: part.try_into().unwrap(),
+                    median: Duration::from_nanos(median_nanos),
+                    spread: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn converts_timings() {
+        let entry = HistoryEntry::new(&[Timings {
+            day: day!(3),
+            part_1: Some(Timing {
+                duration: Duration::from_micros(5),
+                spread: Some(Duration::from_nanos(20)),
+            }),
+            part_2: None,
+            single_part: true,
+            total: Duration::from_micros(5),
+        }]);
+        assert_eq!(
+            entry.timings,
+            vec![PartTiming {
+                day: day!(3),
+                part: Part::One,
+                median: Duration::from_micros(5),
+                spread: Some(Duration::from_nanos(20)),
+            }]
+        );
+    }
+
+    #[test]
+    fn roundtrips_history() {
+        let a = entry(&[(1, 1, 100), (1, 2, 200)]);
+        let b = entry(&[(2, 1, 300)]);
+        let content = [&a, &b]
+            .iter()
+            .map(|x| serde_json::to_string(x).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        // the history keeps its integer nanosecond fields.
+        assert!(content.contains(r#""median_nanos":100,"spread_nanos":null"#));
+        assert_eq!(jsonl::parse::<HistoryEntry>(&content).unwrap(), vec![a, b]);
+    }
+
+    #[test]
+    fn detects_regressions_above_threshold() {
+        let previous = entry(&[(1, 1, 100), (1, 2, 100), (2, 1, 100)]);
+        let current = entry(&[(1, 1, 105), (1, 2, 150), (2, 1, 50), (3, 1, 1000)]);
+        let regressions = find_regressions(&previous, &current, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].day, day!(1));
+        assert_eq!(regressions[0].part, Part::Two);
+        assert_eq!(
+            regressions[0].to_string(),
+            "Day 01 Part 2: 100.0ns → 150.0ns (+50.0%)"
+        );
+    }
+}
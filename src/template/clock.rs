@@ -0,0 +1,116 @@
+/// Source of the current time, so code that waits can be tested without real waiting.
+use std::{
+    thread,
+    time::{Duration, SystemTime},
+};
+
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, duration: Duration);
+}
+
+/// The system clock.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Returns once `deadline` has passed. While waiting, `on_wait` is called with the remaining time about once a second.
+pub fn wait_until(clock: &dyn Clock, deadline: SystemTime, mut on_wait: impl FnMut(Duration)) {
+    while let Ok(remaining) = deadline.duration_since(clock.now()) {
+        if remaining.is_zero() {
+            break;
+        }
+        on_wait(remaining);
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+}
+
+/// Formats the remaining time of a countdown, e.g. `1m 05s`.
+pub fn format_remaining(remaining: Duration) -> String {
+    // round up, so the countdown never shows zero before the deadline.
+    let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    match (secs / 86_400, secs / 3600 % 24, secs / 60 % 60, secs % 60) {
+        (0, 0, 0, s) => format!("{s}s"),
+        (0, 0, m, s) => format!("{m}m {s:02}s"),
+        (0, h, m, s) => format!("{h}h {m:02}m {s:02}s"),
+        (d, h, m, s) => format!("{d}d {h:02}h {m:02}m {s:02}s"),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+pub use fake::FakeClock;
+
+#[cfg(feature = "test_lib")]
+mod fake {
+    use std::{
+        cell::Cell,
+        time::{Duration, SystemTime},
+    };
+
+    use super::Clock;
+
+    /// A clock that only advances when slept on.
+    pub struct FakeClock {
+        now: Cell<SystemTime>,
+    }
+
+    impl FakeClock {
+        pub fn new(now: SystemTime) -> Self {
+            Self {
+                now: Cell::new(now),
+            }
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> SystemTime {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{format_remaining, wait_until, Clock, FakeClock};
+
+    #[test]
+    fn waits_until_deadline() {
+        let deadline = UNIX_EPOCH + Duration::from_millis(2500);
+        let clock = FakeClock::new(UNIX_EPOCH);
+        let mut countdown = vec![];
+
+        wait_until(&clock, deadline, |x| countdown.push(format_remaining(x)));
+
+        assert_eq!(clock.now(), deadline);
+        assert_eq!(countdown, ["3s", "2s", "1s"]);
+
+        // a deadline that passed already returns right away.
+        wait_until(&clock, UNIX_EPOCH, |_| panic!("should not wait"));
+    }
+
+    #[test]
+    fn formats_remaining_time() {
+        assert_eq!(format_remaining(Duration::from_secs(5)), "5s");
+        assert_eq!(format_remaining(Duration::from_millis(4200)), "5s");
+        assert_eq!(format_remaining(Duration::from_secs(65)), "1m 05s");
+        assert_eq!(format_remaining(Duration::from_secs(3723)), "1h 02m 03s");
+        assert_eq!(
+            format_remaining(Duration::from_secs(90_061)),
+            "1d 01h 01m 01s"
+        );
+    }
+}
@@ -1,50 +1,261 @@
-use std::io;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::time::Duration;
+use std::{io, process, thread};
 
 use crate::template::{
+    benchmark_history::{self, HistoryEntry},
+    jsonl,
     readme_benchmarks::{self, Timings},
+    runner::{print_record, BenchConfig, OutputFormat},
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
-
-pub fn handle(is_release: bool, is_timed: bool) {
+use crate::{Day, DaySet, Part};
+
+use child_commands::{RunOptions, SolutionOutput};
+
+/// `compare` is the threshold (in percent) above which a slowdown compared to the previous benchmark run is reported as a regression.
+/// Up to `jobs` days run concurrently, unless the run is timed.
+/// The README benchmarks are only updated if every day is run.
+/// Exits with a non-zero status if any answer does not match its confirmed answer in `data/YYYY/answers`.
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: u16,
+    days: DaySet,
+    is_release: bool,
+    is_timed: bool,
+    format: OutputFormat,
+    bench: BenchConfig,
+    compare: Option<f64>,
+    jobs: usize,
+) {
     let mut timings: Vec<Timings> = vec![];
-
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+    let mut mismatches: Vec<(Day, Part)> = vec![];
+    let is_text = format == OutputFormat::Text;
+
+    let bin_dir = match child_commands::build_solutions(is_release, None) {
+        Ok(bin_dir) => bin_dir,
+        Err(e) => {
+            eprintln!("Failed to build solutions: {e:?}");
+            process::exit(1);
         }
+    };
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+    let options = RunOptions {
+        year,
+        is_timed,
+        format,
+        bench: &bench,
+        workspace: None,
+        bin_dir: &bin_dir,
+        by_day: false,
+    };
+
+    let mut report = |day: Day, output: Result<SolutionOutput, Error>| {
+        let results = match output {
+            Ok(output) => {
+                output.lines.iter().for_each(child_commands::Line::print);
+                output.results
+            }
+            Err(e) => {
+                eprintln!("Failed to run solution for day {day}: {e:?}");
+                vec![]
+            }
+        };
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        mismatches.extend(
+            results
+                .iter()
+                .filter(|x| x.verified == Some(false))
+                .map(|x| (day, x.part)),
+        );
 
-        if output.is_empty() {
-            println!("Not solved.");
+        if results.is_empty() {
+            if is_text {
+                println!("Not solved.");
+            }
         } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+            if format == OutputFormat::Json {
+                results.iter().for_each(print_record);
+            }
+            timings.push(child_commands::collect_timings(&results, day));
         }
-    });
+    };
+
+    if jobs <= 1 || is_timed {
+        if jobs > 1 {
+            eprintln!("Running days sequentially to keep timings accurate.");
+        }
+
+        days.iter().for_each(|day| {
+            print_header(day, days, is_text);
+            report(day, child_commands::run_solution(day, &options, true));
+        });
+    } else {
+        run_parallel(&options, days, jobs, |day, output| {
+            print_header(day, days, is_text);
+            report(day, output);
+        });
+    }
+
+    let mut failed = false;
 
     if is_timed {
-        let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+        let total: Duration = timings.iter().map(|x| x.total).sum();
+        let total_millis = total.as_secs_f64() * 1000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if is_text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
 
         if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
-                Err(_) => {
-                    eprintln!("Failed to update readme with benchmarks.");
+            let entry = HistoryEntry::new(&timings);
+
+            if days != DaySet::all() {
+                if is_text {
+                    println!("Skipped updating README with benchmarks of some days only.");
+                }
+            } else {
+                match readme_benchmarks::update(year, timings, total_millis) {
+                    Ok(()) => {
+                        if is_text {
+                            println!("Successfully updated README with benchmarks.");
+                        }
+                    }
+                    Err(_) => {
+                        eprintln!("Failed to update readme with benchmarks.");
+                    }
                 }
             }
+
+            failed |= record_history(year, &entry, compare, is_text);
+        }
+    }
+
+    if !mismatches.is_empty() {
+        eprintln!();
+        for (day, part) in &mismatches {
+            eprintln!("Answer mismatch: Day {day} Part {part}");
         }
+        failed = true;
+    }
+
+    if failed {
+        process::exit(1);
     }
 }
 
+/// Appends the run to the benchmark history and compares it to the previous run if requested.
+/// Returns whether any regressions were found.
+fn record_history(year: u16, entry: &HistoryEntry, compare: Option<f64>, is_text: bool) -> bool {
+    let report = |line: String| {
+        if is_text {
+            println!("{line}");
+        } else {
+            eprintln!("{line}");
+        }
+    };
+
+    let previous = match benchmark_history::latest(year) {
+        Ok(previous) => previous,
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {e}");
+            None
+        }
+    };
+
+    match benchmark_history::append(year, entry) {
+        Ok(()) => report("Successfully added benchmarks to history.".into()),
+        Err(e) => eprintln!("Failed to add benchmarks to history: {e}"),
+    }
+
+    let Some(threshold) = compare else {
+        return false;
+    };
+
+    let Some(previous) = previous else {
+        report("No previous benchmark run to compare against.".into());
+        return false;
+    };
+
+    let commit = previous.commit.as_deref().unwrap_or("unknown commit");
+    let regressions = benchmark_history::find_regressions(&previous, entry, threshold);
+
+    if regressions.is_empty() {
+        report(format!(
+            "No regressions above {threshold}% compared to {commit}."
+        ));
+        return false;
+    }
+
+    report(format!(
+        "\n{ANSI_BOLD}Regressions above {threshold}% compared to {commit}:{ANSI_RESET}"
+    ));
+    regressions.iter().for_each(|x| report(x.to_string()));
+    true
+}
+
+fn print_header(day: Day, days: DaySet, is_text: bool) {
+    if is_text {
+        if days.iter().next() != Some(day) {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+    }
+}
+
+/// Runs `days` on up to `jobs` threads. `on_done` is called in day order as soon as the output for a day and all days before it is available.
+fn run_parallel(
+    options: &RunOptions,
+    days: DaySet,
+    jobs: usize,
+    mut on_done: impl FnMut(Day, Result<SolutionOutput, Error>),
+) {
+    let days: Vec<Day> = days.iter().collect();
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        let (days, next) = (&days, &next);
+
+        for _ in 0..jobs.min(days.len()) {
+            let tx = tx.clone();
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                let Some(&day) = days.get(index) else {
+                    break;
+                };
+                let output = child_commands::run_solution(day, options, false);
+                if tx.send((index, output)).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut next_index = 0;
+
+        for (index, output) in rx {
+            pending.insert(index, output);
+
+            while let Some(output) = pending.remove(&next_index) {
+                on_done(days[next_index], output);
+                next_index += 1;
+            }
+        }
+    });
+}
+
 #[derive(Debug)]
 pub enum Error {
+    BuildFailed,
     BrokenPipe,
     Parser(String),
     IO(io::Error),
@@ -56,48 +267,177 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+impl From<jsonl::Error> for Error {
+    fn from(e: jsonl::Error) -> Self {
+        match e {
+            jsonl::Error::Parser(s) => Error::Parser(s),
+            jsonl::Error::IO(e) => Error::IO(e),
+        }
+    }
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
+/// This module encapsulates interaction with these binaries, building and invoking them as well as collecting their results.
+/// Solutions report their results to a file passed via [`RESULTS_FILE_ENV`], their stdout is only ever forwarded.
+pub(super) mod child_commands {
+    use super::jsonl;
+    use super::Error;
+    use crate::template::get_bin_path;
+    use crate::template::readme_benchmarks::{Timing, Timings};
+    use crate::template::runner::{
+        BenchConfig, OutputFormat, PartResult, RunnerConfig, RESULTS_FILE_ENV,
+    };
+    use crate::{Day, Part, Puzzle};
+    use serde::Deserialize;
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
+        env,
+        env::consts::EXE_SUFFIX,
+        fs,
+        io::{self, BufRead, BufReader},
+        path::{Path, PathBuf},
         process::{Command, Stdio},
+        sync::mpsc,
         thread,
+        time::Duration,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
+    /// Settings for invoking solution binaries.
+    pub struct RunOptions<'a> {
+        /// The event whose solutions are run.
+        pub year: u16,
+        pub is_timed: bool,
+        pub format: OutputFormat,
+        pub bench: &'a BenchConfig,
+        /// If set, solutions of the project at this path are run instead, but still against the inputs of the current directory.
+        pub workspace: Option<&'a Path>,
+        /// Directory that contains the binaries built by [`build_solutions`].
+        pub bin_dir: &'a Path,
+        /// Whether the solutions in `workspace` are named by day only, e.g. `src/bin/05.rs`.
+        /// These solutions read their input from `data/inputs` of `workspace` and are run there.
+        pub by_day: bool,
+    }
+
+    #[derive(Deserialize)]
+    struct Metadata {
+        target_directory: PathBuf,
+    }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    /// Build all solution binaries at once and return the directory that contains them.
+    /// If `workspace` is set, the project at that path is built instead.
+    pub fn build_solutions(is_release: bool, workspace: Option<&Path>) -> Result<PathBuf, Error> {
+        let mut build_args = vec!["build", "--quiet", "--bins"];
 
         if is_release {
-            args.push("--release");
+            build_args.push("--release");
+        }
+
+        let manifest_path = workspace.map(|x| x.join("Cargo.toml"));
+
+        let cargo = |args: &[&str]| {
+            let mut cmd = Command::new("cargo");
+            cmd.args(args);
+
+            if let Some(manifest_path) = &manifest_path {
+                cmd.arg("--manifest-path").arg(manifest_path);
+                // keep build artifacts of other workspaces apart from ours, but reuse them between invocations.
+                cmd.env("CARGO_TARGET_DIR", Path::new("target").join("workspaces"));
+            }
+
+            cmd
+        };
+
+        let status = cargo(&build_args).status()?;
+        if !status.success() {
+            return Err(Error::BuildFailed);
+        }
+
+        let output = cargo(&["metadata", "--format-version", "1", "--no-deps"])
+            .stderr(Stdio::inherit())
+            .output()?;
+        let metadata: Metadata = serde_json::from_slice(&output.stdout)
+            .map_err(|e| Error::Parser(format!("invalid cargo metadata: {e}")))?;
+
+        let profile = if is_release { "release" } else { "debug" };
+        Ok(metadata.target_directory.join(profile))
+    }
+
+    /// A line of output of a solution, tagged with the stream it should be forwarded to.
+    pub enum Line {
+        Stdout(String),
+        Stderr(String),
+    }
+
+    impl Line {
+        pub fn print(&self) {
+            match self {
+                Line::Stdout(line) => println!("{line}"),
+                Line::Stderr(line) => eprintln!("{line}"),
+            }
         }
+    }
+
+    pub struct SolutionOutput {
+        pub results: Vec<PartResult>,
+        /// Output of the solution, empty if it was streamed.
+        pub lines: Vec<Line>,
+    }
+
+    /// Run the solution bin for a given day and return the results it reported.
+    /// If `stream` is set, output is forwarded while the solution runs, otherwise it is buffered in the returned [`SolutionOutput`].
+    /// In [`OutputFormat::Json`] mode, the output of the solution is forwarded to stderr so stdout only contains records.
+    pub fn run_solution(
+        day: Day,
+        options: &RunOptions,
+        stream: bool,
+    ) -> Result<SolutionOutput, Error> {
+        let mut output = SolutionOutput {
+            results: vec![],
+            lines: vec![],
+        };
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        let puzzle = Puzzle::new(options.year, day);
+
+        let (bin_path, bin_name) = if options.by_day {
+            (
+                PathBuf::from("src/bin").join(format!("{day}.rs")),
+                day.to_string(),
+            )
+        } else {
+            (get_bin_path(puzzle), puzzle.to_string())
+        };
+
+        // skip command invocation for days that have not been scaffolded yet.
+        let bin_path = options
+            .workspace
+            .map_or_else(|| bin_path.clone(), |x| x.join(&bin_path));
+        if !bin_path.exists() {
+            return Ok(output);
         }
 
+        // mirror `--time` flag and benchmark settings to child invocations.
+        let args = RunnerConfig {
+            time: options.is_timed,
+            bench: *options.bench,
+            ..RunnerConfig::default()
+        }
+        .to_args();
+
+        let results_path = get_results_path(puzzle);
+        remove_if_exists(&results_path)?;
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr, results are collected from the results file.
+
+        let bin = options.bin_dir.join(format!("{bin_name}{EXE_SUFFIX}"));
+        let cwd = match options.workspace {
+            Some(workspace) if options.by_day => workspace,
+            _ => Path::new("."),
+        };
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(bin)
             .args(&args)
+            .current_dir(cwd)
+            .env(RESULTS_FILE_ENV, &results_path)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -105,150 +445,220 @@ mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let (tx, rx) = mpsc::channel();
+        let stderr_tx = tx.clone();
+        let format = options.format;
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+        let stdout_thread = thread::spawn(move || {
+            stdout.lines().map_while(Result::ok).for_each(|line| {
+                let line = match format {
+                    OutputFormat::Text => Line::Stdout(line),
+                    OutputFormat::Json => Line::Stderr(line),
+                };
+                let _ = tx.send(line);
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let stderr_thread = thread::spawn(move || {
+            stderr.lines().map_while(Result::ok).for_each(|line| {
+                let _ = stderr_tx.send(Line::Stderr(line));
+            });
+        });
+
+        for line in rx {
+            if stream {
+                line.print();
+            } else {
+                output.lines.push(line);
+            }
         }
 
-        thread.join().unwrap();
+        stdout_thread.join().unwrap();
+        stderr_thread.join().unwrap();
         cmd.wait()?;
 
+        // a missing file means no part finished running.
+        let results = jsonl::read(&results_path);
+        remove_if_exists(&results_path)?;
+        output.results = results?;
         Ok(output)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
+    fn get_results_path(puzzle: Puzzle) -> PathBuf {
+        env::temp_dir().join(format!("aoc-results-{}-{puzzle}.jsonl", std::process::id()))
+    }
+
+    fn remove_if_exists(path: &Path) -> Result<(), Error> {
+        match fs::remove_file(path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn collect_timings(results: &[PartResult], day: Day) -> Timings {
+        let mut timings = Timings {
             day,
             part_1: None,
             part_2: None,
-            total_nanos: 0_f64,
+            single_part: results.iter().any(|x| x.part == Part::Two && !x.applicable),
+            total: Duration::ZERO,
         };
 
-        output
+        results
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
+            .filter(|result| result.solved)
+            .for_each(|result| {
+                let timing = Timing {
+                    duration: result.duration,
+                    spread: result.stats.map(|stats| stats.std_dev),
                 };
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                match result.part {
+                    Part::One => timings.part_1 = Some(timing),
+                    Part::Two => timings.part_2 = Some(timing),
                 }
 
-                timings.total_nanos += nanos;
+                timings.total += result.duration;
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
+    #[cfg(feature = "test_lib")]
+    mod tests {
+        use std::time::Duration;
+        use std::{env, process};
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
+        use super::{collect_timings, remove_if_exists};
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+        use crate::template::jsonl;
+        use crate::template::runner::{BenchStats, PartResult};
+        use crate::{day, Answer, Part};
 
-        Some((str_timing, parsed_timing))
-    }
+        fn parse_results(content: &str) -> Result<Vec<PartResult>, jsonl::Error> {
+            jsonl::parse(content)
+        }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
-    }
+        #[test]
+        fn test_results_file_roundtrip() {
+            let path =
+                env::temp_dir().join(format!("aoc-results-roundtrip-{}.jsonl", process::id()));
+            let samples = [10, 12, 11].map(Duration::from_millis);
+
+            let records = [
+                PartResult {
+                    year: 2023,
+                    day: day!(25),
+                    part: Part::One,
+                    answer: Some(Answer::from_result(&u128::MAX)),
+                    solved: true,
+                    duration: Duration::from_millis(11),
+                    samples: 3,
+                    stats: BenchStats::from_samples(&samples),
+                    applicable: true,
+                    verified: Some(true),
+                },
+                PartResult {
+                    year: 2023,
+                    day: day!(25),
+                    part: Part::Two,
+                    answer: None,
+                    solved: false,
+                    duration: Duration::ZERO,
+                    samples: 0,
+                    stats: None,
+                    applicable: false,
+                    verified: None,
+                },
+            ];
+
+            for record in &records {
+                jsonl::append(&path, record).unwrap();
+            }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
+            let results = jsonl::read::<PartResult>(&path);
+            remove_if_exists(&path).unwrap();
+            assert_eq!(results.unwrap(), records);
 
-        use crate::day;
+            // a solution that didn't report anything.
+            assert!(jsonl::read::<PartResult>(&path).unwrap().is_empty());
+        }
 
         #[test]
         fn test_well_formed() {
-            let res = parse_exec_time(
+            let results = parse_results(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+                    r#"{"year":2023,"day":1,"part":1,"answer":"0","solved":true,"duration_nanos":74,"samples":100000}"#,
+                    r#"{"year":2023,"day":1,"part":2,"answer":"10","solved":true,"duration_nanos":74130000,"samples":99999}"#,
+                    "",
+                ]
+                .join("\n"),
+            )
+            .unwrap();
+            let res = collect_timings(&results, day!(1));
+            assert_eq!(res.total, Duration::from_nanos(74130074));
+            assert_eq!(res.part_1.unwrap().duration, Duration::from_nanos(74));
+            assert_eq!(res.part_2.unwrap().duration, Duration::from_nanos(74130000));
         }
 
         #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+        fn test_answers_are_not_parsed() {
+            let results = parse_results(
+                r#"{"year":2023,"day":1,"part":1,"answer":"Part 1: @ ( ) ms (2s @ 5 samples)","solved":true,"duration_nanos":2000000000,"samples":5}"#,
+            )
+            .unwrap();
+            let res = collect_timings(&results, day!(1));
+            assert_eq!(res.total, Duration::from_secs(2));
+            assert_eq!(res.part_2.is_none(), true);
         }
 
         #[test]
         fn test_missing_parts() {
-            let res = parse_exec_time(
+            let results = parse_results(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
+                    r#"{"year":2023,"day":1,"part":1,"answer":null,"solved":false,"duration_nanos":10,"samples":1}"#,
+                    r#"{"year":2023,"day":1,"part":2,"answer":null,"solved":false,"duration_nanos":10,"samples":1}"#,
+                ]
+                .join("\n"),
+            )
+            .unwrap();
+            let res = collect_timings(&results, day!(1));
+            assert_eq!(res.total, Duration::ZERO);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn test_single_part() {
+            let results = parse_results(
+                &[
+                    r#"{"year":2023,"day":25,"part":1,"answer":"7","solved":true,"duration_nanos":10,"samples":1}"#,
+                    r#"{"year":2023,"day":25,"part":2,"answer":null,"solved":false,"duration_nanos":0,"samples":0,"applicable":false}"#,
+                ]
+                .join("\n"),
+            )
+            .unwrap();
+            assert_eq!(results[0].applicable, true);
+            let res = collect_timings(&results, day!(25));
+            assert_eq!(res.single_part, true);
+            assert_eq!(res.part_2.is_none(), true);
+        }
+
+        #[test]
+        fn test_benched_parts() {
+            let results = parse_results(
+                r#"{"year":2023,"day":1,"part":1,"answer":"0","solved":true,"duration_nanos":2000,"samples":5,"stats":{"min_nanos":1000,"median_nanos":2000,"p95_nanos":3000,"mean_nanos":2100,"std_dev_nanos":400,"outliers":1}}"#,
+            )
+            .unwrap();
+            let res = collect_timings(&results, day!(1));
+            let part_1 = res.part_1.unwrap();
+            assert_eq!(part_1.duration, Duration::from_micros(2));
+            assert_eq!(part_1.spread, Some(Duration::from_nanos(400)));
+        }
+
+        #[test]
+        fn test_malformed_record() {
+            assert!(parse_results("Part 1: 0 (74.13ns @ 100000 samples)").is_err());
+        }
     }
 }
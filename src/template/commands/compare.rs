@@ -0,0 +1,394 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::{self, Command},
+    time::Duration,
+};
+
+use crate::template::{
+    commands::all::child_commands::{self, Line, RunOptions},
+    get_bin_path, get_data_path,
+    runner::{BenchConfig, OutputFormat},
+    ANSI_BOLD, ANSI_RESET,
+};
+use crate::{Day, DaySet, Part, Puzzle};
+
+/// Benchmarks the solutions of the current working tree against the solutions at git revision `rev`.
+/// Both builds run against the inputs of the current working tree.
+pub fn handle(year: u16, days: DaySet, rev: &str, is_release: bool, bench: BenchConfig) {
+    // exit only once `compare` returned, so its worktree has been removed.
+    match compare(year, days, rev, is_release, &bench) {
+        Ok(rows) => {
+            println!();
+            print!("{}", format_table(rev, &rows));
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn compare(
+    year: u16,
+    days: DaySet,
+    rev: &str,
+    is_release: bool,
+    bench: &BenchConfig,
+) -> Result<Vec<Row>, String> {
+    let worktree =
+        Worktree::create(rev).map_err(|e| format!("Failed to check out \"{rev}\": {e}"))?;
+
+    let by_day = names_solutions_by_day(worktree.path(), rev, year, days)?;
+    if by_day {
+        copy_inputs(worktree.path(), year, days)
+            .map_err(|e| format!("Failed to copy inputs to \"{rev}\": {e}"))?;
+    }
+
+    let build = |workspace: Option<&Path>| {
+        child_commands::build_solutions(is_release, workspace)
+            .map_err(|e| format!("Failed to build solutions: {e:?}"))
+    };
+
+    let before_bin_dir = build(Some(worktree.path()))?;
+    let after_bin_dir = build(None)?;
+
+    let before_options = RunOptions {
+        year,
+        is_timed: true,
+        format: OutputFormat::Text,
+        bench,
+        workspace: Some(worktree.path()),
+        bin_dir: &before_bin_dir,
+        by_day,
+    };
+
+    let after_options = RunOptions {
+        workspace: None,
+        bin_dir: &after_bin_dir,
+        by_day: false,
+        ..before_options
+    };
+
+    let mut rows: Vec<Row> = vec![];
+
+    days.iter().for_each(|day| {
+        let before = run(day, &before_options, rev);
+        let after = run(day, &after_options, "working tree");
+
+        for part in Part::ALL {
+            let find =
+                |timings: &[(Part, Duration)]| timings.iter().find(|x| x.0 == part).map(|x| x.1);
+
+            let row = Row {
+                day,
+                part,
+                before: find(&before),
+                after: find(&after),
+            };
+
+            if row.before.is_some() || row.after.is_some() {
+                rows.push(row);
+            }
+        }
+    });
+
+    Ok(rows)
+}
+
+/// Returns whether the solutions at `rev` are named by day only, e.g. `src/bin/05.rs`, as in revisions from before
+/// solutions were named by year and day. Such solutions are assumed to solve the puzzles of `year`.
+fn names_solutions_by_day(
+    worktree: &Path,
+    rev: &str,
+    year: u16,
+    days: DaySet,
+) -> Result<bool, String> {
+    let has_solution = |path: PathBuf| worktree.join(path).exists();
+
+    if days
+        .iter()
+        .any(|day| has_solution(get_bin_path(Puzzle::new(year, day))))
+    {
+        return Ok(false);
+    }
+
+    if days
+        .iter()
+        .any(|day| has_solution(Path::new("src/bin").join(format!("{day}.rs"))))
+    {
+        return Ok(true);
+    }
+
+    Err(format!("\"{rev}\" has no solutions for days {days}."))
+}
+
+/// Copies the inputs of the working tree to `data/inputs` of `worktree`, where solutions named by day read them.
+fn copy_inputs(worktree: &Path, year: u16, days: DaySet) -> io::Result<()> {
+    let dir = worktree.join("data").join("inputs");
+    fs::create_dir_all(&dir)?;
+
+    for day in days.iter() {
+        let input = get_data_path("inputs", Puzzle::new(year, day), "txt");
+        if input.exists() {
+            fs::copy(input, dir.join(format!("{day}.txt")))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs the solution of `day` and returns the time of every solved part.
+/// Solutions that don't report results through [`RESULTS_FILE_ENV`](crate::template::runner::RESULTS_FILE_ENV)
+/// are timed by their text output instead.
+fn run(day: Day, options: &RunOptions, label: &str) -> Vec<(Part, Duration)> {
+    println!("{ANSI_BOLD}Day {day} @ {label}{ANSI_RESET}");
+    println!("------");
+
+    let timings = match child_commands::run_solution(day, options, false) {
+        Ok(output) => {
+            output.lines.iter().for_each(Line::print);
+
+            if output.results.is_empty() {
+                let stdout: Vec<&str> = output
+                    .lines
+                    .iter()
+                    .filter_map(|line| match line {
+                        Line::Stdout(line) => Some(line.as_str()),
+                        Line::Stderr(_) => None,
+                    })
+                    .collect();
+                parse_timings(&stdout)
+            } else {
+                output
+                    .results
+                    .iter()
+                    .filter(|x| x.solved)
+                    .map(|x| (x.part, x.duration))
+                    .collect()
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to run solution for day {day}: {e:?}");
+            vec![]
+        }
+    };
+
+    if timings.is_empty() {
+        println!("Not solved.");
+    }
+
+    println!();
+    timings
+}
+
+/// Parses the times of solved parts from the text output of a solution, e.g. `Part 1: 42 (1.2ms @ 100 samples)`.
+fn parse_timings(lines: &[&str]) -> Vec<(Part, Duration)> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            // a line may start with the intermediate result that was printed before benching.
+            let line = line.rsplit('\r').next()?.trim_end();
+
+            let (part, rest) = line.strip_prefix("Part ")?.split_once(':')?;
+            let part: Part = part.parse().ok()?;
+
+            let (_, timing) = rest.strip_suffix(')')?.rsplit_once('(')?;
+            let duration = timing.split(['@', '±', ',']).next()?.trim();
+            Some((part, parse_duration(duration)?))
+        })
+        .collect()
+}
+
+/// Parses a duration in the `Debug` format of [`Duration`], e.g. `74.1ns` or `2s`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let (value, nanos_per_unit) = [("ns", 1.0), ("µs", 1e3), ("ms", 1e6), ("s", 1e9)]
+        .iter()
+        .find_map(|&(unit, nanos)| Some((s.strip_suffix(unit)?, nanos)))?;
+
+    let nanos = value.parse::<f64>().ok()? * nanos_per_unit;
+    (nanos.is_finite() && nanos >= 0.0).then(|| Duration::from_secs_f64(nanos / 1e9))
+}
+
+/// The timings of one solution part at both revisions.
+struct Row {
+    day: Day,
+    part: Part,
+    before: Option<Duration>,
+    after: Option<Duration>,
+}
+
+impl Row {
+    /// How many times faster the working tree is compared to the other revision.
+    fn speedup(&self) -> Option<f64> {
+        let (before, after) = (self.before?, self.after?);
+        if after.is_zero() {
+            return None;
+        }
+        Some(before.as_secs_f64() / after.as_secs_f64())
+    }
+}
+
+fn format_table(rev: &str, rows: &[Row]) -> String {
+    let format_timing = |x: Option<Duration>| x.map_or_else(|| "-".into(), |x| format!("{x:.1?}"));
+    let width = rev.len().max(12);
+
+    let mut lines = vec![
+        format!(
+            "{ANSI_BOLD}{:<4} {:<4} {:>width$} {:>width$} {:>8}{ANSI_RESET}",
+            "Day", "Part", rev, "working tree", "Speedup"
+        ),
+        "-".repeat(4 + 1 + 4 + 1 + width + 1 + width + 1 + 8),
+    ];
+
+    for row in rows {
+        lines.push(format!(
+            "{:<4} {:<4} {:>width$} {:>width$} {:>8}",
+            row.day.to_string(),
+            row.part,
+            format_timing(row.before),
+            format_timing(row.after),
+            row.speedup()
+                .map_or_else(|| "-".into(), |x| format!("{x:.2}x"))
+        ));
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// A temporary git worktree that is removed when dropped.
+struct Worktree {
+    path: PathBuf,
+}
+
+impl Worktree {
+    fn create(rev: &str) -> Result<Self, String> {
+        let path = env::temp_dir().join(format!("aoc-worktree-{}", process::id()));
+
+        let output = Command::new("git")
+            .args(["worktree", "add", "--detach"])
+            .arg(&path)
+            .arg(rev)
+            .output()
+            .map_err(|e| format!("could not call git: {e}"))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        // pin dependencies to the same versions as the working tree if possible.
+        let _ = fs::copy("Cargo.lock", path.join("Cargo.lock"));
+
+        Ok(Self { path })
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let removed = Command::new("git")
+            .args(["worktree", "remove", "--force"])
+            .arg(&self.path)
+            .status();
+
+        if !removed.is_ok_and(|x| x.success()) {
+            eprintln!(
+                "Failed to remove temporary worktree \"{}\".",
+                self.path.display()
+            );
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+    use std::{env, fs, process};
+
+    use super::{format_table, names_solutions_by_day, parse_timings, Row};
+    use crate::{day, DaySet, Part};
+
+    #[test]
+    fn speedup() {
+        let row = Row {
+            day: day!(1),
+            part: Part::One,
+            before: Some(Duration::from_millis(30)),
+            after: Some(Duration::from_millis(10)),
+        };
+        assert_eq!(row.speedup(), Some(3.0));
+
+        let row = Row { after: None, ..row };
+        assert_eq!(row.speedup(), None);
+    }
+
+    #[test]
+    fn formats_table() {
+        let rows = [
+            Row {
+                day: day!(5),
+                part: Part::Two,
+                before: Some(Duration::from_millis(20)),
+                after: Some(Duration::from_millis(10)),
+            },
+            Row {
+                day: day!(6),
+                part: Part::One,
+                before: None,
+                after: Some(Duration::from_micros(5)),
+            },
+        ];
+        let table = format_table("main", &rows);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[2], "05   2          20.0ms       10.0ms    2.00x");
+        assert_eq!(lines[3], "06   1               -        5.0µs        -");
+    }
+
+    #[test]
+    fn detects_solution_layout() {
+        let dir = env::temp_dir().join(format!("aoc-compare-revision-{}", process::id()));
+        fs::create_dir_all(dir.join("src/bin")).unwrap();
+
+        let days: DaySet = "1-3".parse().unwrap();
+        let empty = names_solutions_by_day(&dir, "old", 2023, days);
+
+        fs::write(dir.join("src/bin/02.rs"), "").unwrap();
+        let by_day = names_solutions_by_day(&dir, "old", 2023, days);
+
+        fs::write(dir.join("src/bin/2023-02.rs"), "").unwrap();
+        let by_year = names_solutions_by_day(&dir, "new", 2023, days);
+        let outside_days = names_solutions_by_day(&dir, "new", 2023, "4".parse().unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(empty.is_err());
+        assert_eq!(by_day, Ok(true));
+        assert_eq!(by_year, Ok(false));
+        assert!(outside_days.is_err());
+    }
+
+    #[test]
+    fn parses_timings() {
+        let timings = parse_timings(&[
+            "Part 1: \x1b[1m@ ( ) ms\x1b[0m > benching\rPart 1: \x1b[1m@ ( ) ms\x1b[0m (74.13µs @ 100 samples)",
+            "Part 2: ▼ (2s)",
+            "#.#",
+        ]);
+        assert_eq!(
+            timings,
+            [
+                (Part::One, Duration::from_nanos(74_130)),
+                (Part::Two, Duration::from_secs(2)),
+            ]
+        );
+
+        let timings = parse_timings(&[
+            "Part 1: 42 ✓ (1.5ms ± 100.0µs, min 1.2ms, p95 1.9ms @ 10 samples)",
+            "Part 2: ✖             ",
+        ]);
+        assert_eq!(timings, [(Part::One, Duration::from_micros(1500))]);
+    }
+}
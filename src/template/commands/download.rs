@@ -1,15 +1,316 @@
-use crate::template::aoc_cli;
-use crate::Day;
+use std::fs;
+use std::io::{stdout, Write};
+use std::path::{Path, PathBuf};
 use std::process;
+use std::time::{Duration, SystemTime};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+use crate::template::backend::{self, AocBackend};
+use crate::template::clock::{format_remaining, wait_until, Clock, SystemClock};
+use crate::template::{get_data_dir, get_data_path};
+use crate::{Day, Puzzle};
+
+/// With `wait`, a puzzle that is not unlocked yet is downloaded as soon as it unlocks.
+pub fn handle(backend: &dyn AocBackend, puzzle: Puzzle, wait: bool) {
+    let Puzzle { year, day } = puzzle;
+    let mut is_waiting = false;
+
+    let unlocked = wait_for_unlock(&SystemClock, puzzle.unlock_time(), wait, |remaining| {
+        is_waiting = true;
+        print!(
+            "\r⏳ Day {day} unlocks in {}. Press Ctrl-C to cancel.   ",
+            format_remaining(remaining)
+        );
+        let _ = stdout().flush();
+    });
+
+    if is_waiting {
+        println!();
+    }
+
+    if let Err(remaining) = unlocked {
+        eprintln!(
+            "Day {day} of {year} unlocks in {}. Pass --wait to download it as soon as it unlocks.",
+            format_remaining(remaining)
+        );
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    let input_path = get_data_path("inputs", puzzle, "txt");
+    let puzzle_path = get_data_path("puzzles", puzzle, "md");
+
+    if let Err(e) = download(backend, puzzle, &input_path, &puzzle_path) {
+        eprintln!("Failed to download puzzle: {e}");
         process::exit(1);
     };
+
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+}
+
+/// Downloads every day in `days` whose input is missing, waiting `delay` between downloads.
+pub fn handle_backfill(backend: &dyn AocBackend, year: u16, days: &[Day], delay: Duration) {
+    let data_dir = get_data_dir(year);
+
+    let summary = backfill(
+        backend,
+        &SystemClock,
+        days,
+        year,
+        (&data_dir.join("inputs"), &data_dir.join("puzzles")),
+        delay,
+        |day, status| match status {
+            Status::Fetched => println!("🎄 Day {day}: downloaded."),
+            Status::Skipped => println!("Day {day}: skipped, input exists."),
+            Status::Locked => println!("Day {day}: skipped, not unlocked yet."),
+            Status::Failed(e) => eprintln!("Day {day}: failed to download: {e}"),
+        },
+    );
+
+    println!("---");
+    println!(
+        "Downloaded {}, skipped {}, failed {}.",
+        summary.fetched, summary.skipped, summary.failed
+    );
+
+    if summary.failed > 0 {
+        process::exit(1);
+    }
+}
+
+fn get_input_path(dir: impl AsRef<Path>, day: Day) -> PathBuf {
+    dir.as_ref().join(format!("{day}.txt"))
+}
+
+fn get_puzzle_path(dir: impl AsRef<Path>, day: Day) -> PathBuf {
+    dir.as_ref().join(format!("{day}.md"))
+}
+
+/// What happened to a day during a backfill.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Status {
+    Fetched,
+    /// The input exists already. Empty inputs, e.g. created by `scaffold`, are downloaded.
+    Skipped,
+    Locked,
+    Failed(String),
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Summary {
+    fetched: usize,
+    skipped: usize,
+    failed: usize,
+}
+
+fn backfill(
+    backend: &dyn AocBackend,
+    clock: &dyn Clock,
+    days: &[Day],
+    year: u16,
+    (inputs_dir, puzzles_dir): (&Path, &Path),
+    delay: Duration,
+    mut on_day: impl FnMut(Day, &Status),
+) -> Summary {
+    let mut summary = Summary::default();
+    let mut is_first_request = true;
+
+    for &day in days {
+        let input_path = get_input_path(inputs_dir, day);
+
+        let status = if fs::metadata(&input_path).is_ok_and(|x| x.len() > 0) {
+            Status::Skipped
+        } else if clock.now() < day.unlock_time(year) {
+            Status::Locked
+        } else {
+            // be polite and space out requests.
+            if !is_first_request {
+                clock.sleep(delay);
+            }
+            is_first_request = false;
+
+            match download(
+                backend,
+                Puzzle::new(year, day),
+                &input_path,
+                &get_puzzle_path(puzzles_dir, day),
+            ) {
+                Ok(()) => Status::Fetched,
+                Err(e) => Status::Failed(e.to_string()),
+            }
+        };
+
+        match status {
+            Status::Fetched => summary.fetched += 1,
+            Status::Skipped | Status::Locked => summary.skipped += 1,
+            Status::Failed(_) => summary.failed += 1,
+        }
+
+        on_day(day, &status);
+    }
+
+    summary
+}
+
+/// Returns once `unlock` has passed, see [`wait_until`].
+/// If it has not and `wait` is `false`, returns the remaining time instead.
+fn wait_for_unlock(
+    clock: &dyn Clock,
+    unlock: SystemTime,
+    wait: bool,
+    on_wait: impl FnMut(Duration),
+) -> Result<(), Duration> {
+    match unlock.duration_since(clock.now()) {
+        Ok(remaining) if !remaining.is_zero() && !wait => Err(remaining),
+        _ => {
+            wait_until(clock, unlock, on_wait);
+            Ok(())
+        }
+    }
+}
+
+fn download(
+    backend: &dyn AocBackend,
+    puzzle: Puzzle,
+    input_path: &Path,
+    puzzle_path: &Path,
+) -> Result<(), backend::Error> {
+    let input = backend.input(puzzle)?;
+    let description = backend.puzzle(puzzle)?;
+
+    for path in [input_path, puzzle_path] {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+    }
+
+    fs::write(input_path, input)?;
+    fs::write(puzzle_path, description)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{backfill, download, wait_for_unlock, Status, Summary};
+    use crate::template::backend::FakeBackend;
+    use crate::template::clock::{format_remaining, Clock, FakeClock};
+    use crate::{day, Puzzle};
+
+    #[test]
+    fn refuses_locked_puzzles() {
+        let unlock = day!(5).unlock_time(2023);
+        let clock = FakeClock::new(unlock - Duration::from_secs(90));
+        assert_eq!(
+            wait_for_unlock(&clock, unlock, false, |_| {}),
+            Err(Duration::from_secs(90))
+        );
+
+        let clock = FakeClock::new(unlock);
+        assert_eq!(wait_for_unlock(&clock, unlock, false, |_| {}), Ok(()));
+    }
+
+    #[test]
+    fn waits_for_unlock() {
+        let unlock = UNIX_EPOCH + Duration::from_millis(2500);
+        let clock = FakeClock::new(UNIX_EPOCH);
+        let mut countdown = vec![];
+
+        let result = wait_for_unlock(&clock, unlock, true, |x| {
+            countdown.push(format_remaining(x));
+        });
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(clock.now(), unlock);
+        assert_eq!(countdown, ["3s", "2s", "1s"]);
+    }
+
+    #[test]
+    fn writes_input_and_puzzle() {
+        let puzzle = Puzzle::new(2023, day!(1));
+        let mut backend = FakeBackend::default();
+        backend.inputs.insert(puzzle, "1abc2\n".into());
+        backend.puzzles.insert(puzzle, "## --- Day 1 ---\n".into());
+
+        let dir = env::temp_dir().join(format!("aoc-download-{}", process::id()));
+        // directories are created as needed.
+        let input_path = dir.join("inputs").join("01.txt");
+        let puzzle_path = dir.join("puzzles").join("01.md");
+
+        download(&backend, puzzle, &input_path, &puzzle_path).unwrap();
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "1abc2\n");
+        assert_eq!(
+            fs::read_to_string(&puzzle_path).unwrap(),
+            "## --- Day 1 ---\n"
+        );
+
+        assert!(download(
+            &backend,
+            Puzzle::new(2022, day!(1)),
+            &input_path,
+            &puzzle_path
+        )
+        .is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn backfills_missing_inputs() {
+        let mut backend = FakeBackend::default();
+        for day in [day!(1), day!(3)] {
+            let puzzle = Puzzle::new(2023, day);
+            backend.inputs.insert(puzzle, format!("input {day}"));
+            backend.puzzles.insert(puzzle, format!("puzzle {day}"));
+        }
+
+        let dir = env::temp_dir().join(format!("aoc-backfill-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("02.txt"), "existing").unwrap();
+        // scaffold creates empty inputs.
+        fs::write(dir.join("03.txt"), "").unwrap();
+
+        let start = day!(5).unlock_time(2023) - Duration::from_secs(10);
+        let clock = FakeClock::new(start);
+        let days = [day!(1), day!(2), day!(3), day!(4), day!(5)];
+        let mut statuses = vec![];
+
+        let summary = backfill(
+            &backend,
+            &clock,
+            &days,
+            2023,
+            (&dir, &dir),
+            Duration::from_secs(2),
+            |day, status| statuses.push((day.into_inner(), status.clone())),
+        );
+
+        assert_eq!(fs::read_to_string(dir.join("02.txt")).unwrap(), "existing");
+        assert_eq!(fs::read_to_string(dir.join("03.txt")).unwrap(), "input 03");
+        assert_eq!(fs::read_to_string(dir.join("03.md")).unwrap(), "puzzle 03");
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            summary,
+            Summary {
+                fetched: 2,
+                skipped: 2,
+                failed: 1,
+            }
+        );
+        assert_eq!(statuses[0], (1, Status::Fetched));
+        assert_eq!(statuses[1], (2, Status::Skipped));
+        assert_eq!(statuses[2], (3, Status::Fetched));
+        assert!(matches!(statuses[3], (4, Status::Failed(_))));
+        assert_eq!(statuses[4], (5, Status::Locked));
+        // requests are spaced out, the first one is not delayed.
+        assert_eq!(clock.now(), start + Duration::from_secs(4));
+    }
 }
@@ -0,0 +1,252 @@
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
+use std::process;
+
+use crate::template::{get_bin_path, get_data_dir, get_data_path, puzzle};
+use crate::{Answer, Part, Puzzle};
+
+/// Proposes the code blocks of a downloaded puzzle as example input and the emphasized values as expected answers.
+/// Existing examples and expected values are only replaced with `overwrite`.
+pub fn handle(puzzle: Puzzle, overwrite: bool) {
+    let day = puzzle.day;
+    let puzzle_path = get_data_path("puzzles", puzzle, "md");
+    let module_path = get_bin_path(puzzle);
+    let examples_dir = get_data_dir(puzzle.year).join("examples");
+
+    let markdown = fs::read_to_string(&puzzle_path).unwrap_or_else(|e| {
+        eprintln!(
+            "Failed to read puzzle \"{}\": {e}. Run `cargo download {day}` first.",
+            puzzle_path.display()
+        );
+        process::exit(1);
+    });
+
+    let (part_one, part_two) = puzzle::split_parts(&markdown);
+    let blocks = puzzle::code_blocks(part_one);
+
+    if blocks.is_empty() {
+        println!("No code blocks found in \"{}\".", puzzle_path.display());
+    } else {
+        propose_example(
+            "example input",
+            &blocks,
+            &examples_dir.join(format!("{day}.txt")),
+            overwrite,
+        );
+    }
+
+    // part two gets its own example only if it shows a new one.
+    let part_two_blocks: Vec<String> = part_two
+        .map(puzzle::code_blocks)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|x| !blocks.contains(x))
+        .collect();
+
+    if !part_two_blocks.is_empty() {
+        propose_example(
+            "example input of part 2",
+            &part_two_blocks,
+            &examples_dir.join(format!("{day}-2.txt")),
+            overwrite,
+        );
+    }
+
+    let mut module = match fs::read_to_string(&module_path) {
+        Ok(module) => module,
+        Err(e) => {
+            eprintln!(
+                "Failed to read module \"{}\": {e}. Run `cargo scaffold {day}` first.",
+                module_path.display()
+            );
+            process::exit(1);
+        }
+    };
+
+    for (part, text) in [(Part::One, Some(part_one)), (Part::Two, part_two)] {
+        let Some(text) = text else {
+            continue;
+        };
+
+        let values = puzzle::emphasized_values(text);
+
+        if values.is_empty() {
+            println!("No expected answer found for part {part}.");
+            continue;
+        }
+
+        // the answer to an example is usually the last value a part emphasizes.
+        let expected = &values[pick(
+            &format!("expected answer of part {part}"),
+            &values,
+            values.len() - 1,
+        )];
+
+        match fill_expected(&module, part, expected, overwrite) {
+            Some(updated) => {
+                module = updated;
+                println!("🎄 Expecting {expected} in the part {part} example test.");
+            }
+            None => println!(
+                "Kept the expected value of the part {part} example test. Pass --overwrite to replace it."
+            ),
+        }
+    }
+
+    if let Err(e) = fs::write(&module_path, module) {
+        eprintln!("Failed to write module \"{}\": {e}", module_path.display());
+        process::exit(1);
+    }
+}
+
+fn propose_example(label: &str, blocks: &[String], example_path: &Path, overwrite: bool) {
+    let example = &blocks[pick(label, blocks, 0)];
+
+    match write_example(example_path, example, overwrite) {
+        Ok(true) => println!("🎄 Wrote example to \"{}\".", example_path.display()),
+        Ok(false) => {
+            println!(
+                "Kept existing example in \"{}\". Pass --overwrite to replace it.",
+                example_path.display()
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Lets the user pick one of several candidates in a terminal. Returns `default` otherwise.
+fn pick(label: &str, candidates: &[String], default: usize) -> usize {
+    if candidates.len() < 2 || !io::stdin().is_terminal() {
+        return default;
+    }
+
+    println!("Found {} candidates for the {label}:", candidates.len());
+
+    for (i, candidate) in candidates.iter().enumerate() {
+        let mut lines = candidate.lines();
+        let first = lines.next().unwrap_or_default();
+        let more = lines.count();
+
+        if more > 0 {
+            println!("  {}) {first} (+{more} lines)", i + 1);
+        } else {
+            println!("  {}) {first}", i + 1);
+        }
+    }
+
+    loop {
+        print!("Pick the {label} [{}]: ", default + 1);
+        let _ = io::stdout().flush();
+
+        let mut line = String::new();
+        if io::stdin().read_line(&mut line).unwrap_or(0) == 0 {
+            return default;
+        }
+
+        match line.trim() {
+            "" => return default,
+            x => match x.parse::<usize>() {
+                Ok(n) if (1..=candidates.len()).contains(&n) => return n - 1,
+                _ => println!("Enter a number between 1 and {}.", candidates.len()),
+            },
+        }
+    }
+}
+
+/// Writes `example` to `path` unless the file has other content already. Returns whether the file was written.
+fn write_example(path: &Path, example: &str, overwrite: bool) -> io::Result<bool> {
+    let existing = fs::read_to_string(path).unwrap_or_default();
+
+    if !existing.trim().is_empty() && !overwrite {
+        return Ok(existing == example);
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(path, example)?;
+    Ok(true)
+}
+
+/// Sets the expected value of the example test of `part` in a scaffolded module.
+/// Returns `None` if the test is missing or expects a value already and `overwrite` is not set.
+fn fill_expected(module: &str, part: Part, expected: &str, overwrite: bool) -> Option<String> {
+    let start = module.find(&format!("fn test_part_{}_example()", part.name()))?;
+    let end = start + module[start..].find("\n    }")?;
+
+    let prefix = "assert_eq!(result, ";
+    let value_start = start + module[start..end].find(prefix)? + prefix.len();
+    let value_end = value_start + module[value_start..end].find(");")?;
+
+    if &module[value_start..value_end] != "None" && !overwrite {
+        return None;
+    }
+
+    let value = match Answer::from(expected) {
+        Answer::Integer(x) => format!("Some({x})"),
+        _ => format!("Some(String::from({expected:?}))"),
+    };
+
+    Some(format!(
+        "{}{value}{}",
+        &module[..value_start],
+        &module[value_end..]
+    ))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{fill_expected, write_example};
+    use crate::template::commands::scaffold;
+    use crate::{day, Part, Puzzle};
+
+    fn module() -> String {
+        scaffold::module(Puzzle::new(2023, day!(1)))
+    }
+
+    #[test]
+    fn fills_expected_values() {
+        let module = fill_expected(&module(), Part::One, "142", false).unwrap();
+        assert!(module.contains(
+            "read_example(PUZZLE, Part::One, None));\n        assert_eq!(result, Some(142));"
+        ));
+        assert!(module.contains(
+            "read_example(PUZZLE, Part::Two, None));\n        assert_eq!(result, None);"
+        ));
+
+        let module = fill_expected(&module, Part::Two, "AB,C", false).unwrap();
+        assert!(module.contains("assert_eq!(result, Some(String::from(\"AB,C\")));"));
+    }
+
+    #[test]
+    fn keeps_expected_values() {
+        let module = fill_expected(&module(), Part::Two, "6", false).unwrap();
+        assert_eq!(fill_expected(&module, Part::Two, "7", false), None);
+        assert!(fill_expected(&module, Part::Two, "7", true)
+            .unwrap()
+            .contains("assert_eq!(result, Some(7));"));
+        assert_eq!(fill_expected("fn main() {}", Part::One, "7", true), None);
+    }
+
+    #[test]
+    fn keeps_existing_examples() {
+        let path = env::temp_dir().join(format!("aoc-examples-{}.txt", process::id()));
+
+        // scaffold creates empty examples.
+        fs::write(&path, "").unwrap();
+        assert!(write_example(&path, "1\n2\n", false).unwrap());
+        assert!(!write_example(&path, "3\n", false).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n");
+
+        assert!(write_example(&path, "3\n", true).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3\n");
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,157 @@
+use std::fs;
+use std::process;
+
+use crate::template::answers::{self, Answers};
+use crate::template::{get_data_dir, get_data_path, puzzle};
+use crate::{all_days, Answer, Part, Puzzle};
+
+/// Records the answers confirmed by downloaded puzzle pages in the answers store.
+/// Stored answers are never replaced; disagreements are reported instead.
+pub fn handle(year: u16) {
+    let mut added = 0;
+    let mut conflicts = 0;
+
+    for day in all_days() {
+        let puzzle = Puzzle::new(year, day);
+
+        let Ok(markdown) = fs::read_to_string(get_data_path("puzzles", puzzle, "md")) else {
+            continue;
+        };
+
+        let confirmed = puzzle::confirmed_answers(&markdown);
+
+        if confirmed.is_empty() {
+            continue;
+        }
+
+        let mut answers = answers::read(puzzle).unwrap_or_else(|e| {
+            eprintln!("Failed to read answers of day {day}: {e}");
+            process::exit(1);
+        });
+
+        let changes = harvest(&mut answers, &confirmed);
+
+        for change in &changes {
+            match change {
+                Change::Added { part, answer } => {
+                    added += 1;
+                    println!("🎄 Day {day} Part {part}: recorded {answer}.");
+                }
+                Change::Conflict {
+                    part,
+                    stored,
+                    confirmed,
+                } => {
+                    conflicts += 1;
+                    eprintln!(
+                        "Day {day} Part {part}: stored answer {stored} disagrees with the puzzle page, which confirms {confirmed}."
+                    );
+                }
+            }
+        }
+
+        if changes.iter().any(|x| matches!(x, Change::Added { .. })) {
+            if let Err(e) = answers::write(puzzle, &answers) {
+                eprintln!("Failed to write answers of day {day}: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    println!("---");
+    println!("Recorded {added} answer(s), found {conflicts} disagreement(s).");
+
+    if conflicts > 0 {
+        eprintln!(
+            "Fix or remove the stored answers in \"{}\" and run this command again.",
+            get_data_dir(year).join("answers").display()
+        );
+        process::exit(1);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Change {
+    Added {
+        part: Part,
+        answer: Answer,
+    },
+    Conflict {
+        part: Part,
+        stored: Answer,
+        confirmed: Answer,
+    },
+}
+
+/// Adds the `confirmed` answers that are not stored yet to `answers`.
+fn harvest(answers: &mut Answers, confirmed: &[Answer]) -> Vec<Change> {
+    let mut changes = vec![];
+
+    for (part, answer) in Part::ALL.into_iter().zip(confirmed) {
+        match answers.get(part) {
+            None => {
+                answers.set(part, answer.clone());
+                changes.push(Change::Added {
+                    part,
+                    answer: answer.clone(),
+                });
+            }
+            Some(stored) if stored == answer => {}
+            Some(stored) => changes.push(Change::Conflict {
+                part,
+                stored: stored.clone(),
+                confirmed: answer.clone(),
+            }),
+        }
+    }
+
+    changes
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{harvest, Change};
+    use crate::template::answers::Answers;
+    use crate::{Answer, Part};
+
+    #[test]
+    fn records_new_answers() {
+        let mut answers = Answers::default();
+        let changes = harvest(&mut answers, &["54388".into(), "53515".into()]);
+
+        assert_eq!(answers.get(Part::One), Some(&Answer::Integer(54388)));
+        assert_eq!(answers.get(Part::Two), Some(&Answer::Integer(53515)));
+        assert_eq!(
+            changes,
+            [
+                Change::Added {
+                    part: Part::One,
+                    answer: Answer::Integer(54388)
+                },
+                Change::Added {
+                    part: Part::Two,
+                    answer: Answer::Integer(53515)
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_disagreements() {
+        let mut answers = Answers {
+            part_one: Some(Answer::Integer(54388)),
+            part_two: Some(Answer::Integer(1)),
+        };
+        let changes = harvest(&mut answers, &["54388".into(), "53515".into()]);
+
+        assert_eq!(answers.get(Part::Two), Some(&Answer::Integer(1)));
+        assert_eq!(
+            changes,
+            [Change::Conflict {
+                part: Part::Two,
+                stored: Answer::Integer(1),
+                confirmed: Answer::Integer(53515)
+            }]
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use std::io::{self, IsTerminal};
+use std::process;
+
+use crate::template::aoc_client::AocClient;
+use crate::template::session::{self, Session};
+
+pub fn handle(year: u16) {
+    let token = read_token().unwrap_or_else(|e| {
+        eprintln!("Failed to read session cookie: {e}");
+        process::exit(1);
+    });
+
+    let session = Session::parse(&token).unwrap_or_else(|e| {
+        eprintln!("Failed to log in: {e}");
+        process::exit(1);
+    });
+
+    let user = match AocClient::from_env_with(session.clone()).whoami(year) {
+        Ok(Some(user)) => user,
+        Ok(None) => {
+            eprintln!("Failed to log in: the session cookie is expired or invalid.");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to check session cookie: {e}");
+            process::exit(1);
+        }
+    };
+
+    match session::store(&session) {
+        Ok(path) => println!(
+            "🎄 Logged in as {user}. Stored session cookie in \"{}\".",
+            path.display()
+        ),
+        Err(e) => {
+            eprintln!("Failed to store session cookie: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Reads the token from stdin without echoing it, so it does not end up in the terminal or shell history.
+fn read_token() -> io::Result<String> {
+    if io::stdin().is_terminal() {
+        rpassword::prompt_password("Paste your session cookie: ")
+    } else {
+        let mut token = String::new();
+        io::stdin().read_line(&mut token)?;
+        Ok(token)
+    }
+}
@@ -0,0 +1,18 @@
+use std::process;
+
+use crate::template::session;
+
+pub fn handle() {
+    match session::remove() {
+        Ok(true) => println!("🎄 Logged out."),
+        Ok(false) => println!("Not logged in."),
+        Err(e) => {
+            eprintln!("Failed to remove session cookie: {e}");
+            process::exit(1);
+        }
+    }
+
+    if std::env::var_os("ADVENT_OF_CODE_SESSION").is_some() {
+        eprintln!("ADVENT_OF_CODE_SESSION is still set and will be used.");
+    }
+}
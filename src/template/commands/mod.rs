@@ -1,5 +1,11 @@
 pub mod all;
+pub mod compare;
 pub mod download;
+pub mod examples;
+pub mod harvest;
+pub mod login;
+pub mod logout;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod whoami;
@@ -1,16 +1,33 @@
+use std::fs;
+use std::path::Path;
 use std::process;
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::backend::{self, AocBackend};
+use crate::template::get_data_path;
+use crate::Puzzle;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(backend: &dyn AocBackend, puzzle: Puzzle) {
+    let puzzle_path = get_data_path("puzzles", puzzle, "md");
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    match read(backend, puzzle, &puzzle_path) {
+        Ok(puzzle) => println!("{puzzle}"),
+        Err(e) => {
+            eprintln!("Failed to read puzzle: {e}");
+            process::exit(1);
+        }
     };
 }
+
+/// Fetches the puzzle description and stores it in `puzzle_path`.
+fn read(
+    backend: &dyn AocBackend,
+    puzzle: Puzzle,
+    puzzle_path: &Path,
+) -> Result<String, backend::Error> {
+    let description = backend.puzzle(puzzle)?;
+    if let Some(dir) = puzzle_path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(puzzle_path, &description)?;
+    Ok(description)
+}
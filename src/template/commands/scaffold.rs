@@ -1,12 +1,14 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::Day;
+use crate::template::{aoc_client, get_bin_path, get_data_dir, get_data_path};
+use crate::{DaySet, Puzzle};
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(YEAR, DAY_NUMBER);
 
 pub fn part_one(_input: &str) -> Option<u32> {
     return None;
@@ -19,90 +21,141 @@ pub fn part_two(_input: &str) -> Option<u32> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::{template::read_example, Part};
 
     #[test]
     fn test_part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&read_example(PUZZLE, Part::One, None));
         assert_eq!(result, None);
     }
 
     #[test]
     fn run_part_one_actual() {
-        let result = part_one(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_one(&advent_of_code::template::read_file("inputs", PUZZLE));
         assert!(result.is_some());
         println!("{}", result.unwrap());
     }
 
     #[test]
     fn test_part_two_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&read_example(PUZZLE, Part::Two, None));
         assert_eq!(result, None);
     }
 
     #[test]
     fn run_part_two_actual() {
-        let result = part_two(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_two(&advent_of_code::template::read_file("inputs", PUZZLE));
         assert!(result.is_some());
         println!("{}", result.unwrap());
     }
 }
 "#;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+/// Returns the contents of a newly scaffolded solution of `puzzle`.
+pub(crate) fn module(puzzle: Puzzle) -> String {
+    MODULE_TEMPLATE
+        .replace("YEAR", &puzzle.year.to_string())
+        .replace("DAY_NUMBER", &puzzle.day.into_inner().to_string())
+}
+
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Scaffolds the solution, input and example of every day in `days`.
+/// Days that fail to scaffold, e.g. because their solution exists already, are skipped.
+pub fn handle(year: u16, days: DaySet) {
+    let failed = days
+        .iter()
+        .filter(|&day| !scaffold(Puzzle::new(year, day)))
+        .count();
+
+    if failed == days.len() {
+        process::exit(1);
+    }
+
+    println!("---");
+
+    let year_arg = if aoc_client::event_year().ok() == Some(year) {
+        String::new()
+    } else {
+        format!(" --year {year}")
+    };
+
+    match days.iter().next() {
+        Some(day) if days.len() == 1 => {
+            println!("🎄 Type `cargo solve {day}{year_arg}` to run your solution.");
+        }
+        _ => println!("🎄 Type `cargo all {days}{year_arg}` to run your solutions."),
+    }
+
+    if failed > 0 {
+        process::exit(1);
+    }
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Scaffolds a single day, returns whether it succeeded.
+fn scaffold(puzzle: Puzzle) -> bool {
+    let year = puzzle.year;
+    let input_path = get_data_path("inputs", puzzle, "txt");
+    let example_path = get_data_path("examples", puzzle, "txt");
+    let module_path = get_bin_path(puzzle);
+
+    for folder in ["inputs", "examples"] {
+        if let Err(e) = fs::create_dir_all(get_data_dir(year).join(folder)) {
+            eprintln!("Failed to create data directory: {e}");
+            return false;
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
         Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+            eprintln!(
+                "Failed to create module file \"{}\": {e}",
+                module_path.display()
+            );
+            return false;
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(module(puzzle).as_bytes()) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+            return false;
         }
     }
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+            return false;
         }
     }
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+            return false;
         }
     }
 
-    println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    true
 }
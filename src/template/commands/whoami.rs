@@ -0,0 +1,23 @@
+use std::process;
+
+use crate::template::aoc_client::AocClient;
+use crate::template::session;
+
+pub fn handle(year: u16) {
+    let Some(session) = session::load() else {
+        eprintln!("Not logged in. Run \"cargo aoc-login\" to log in.");
+        process::exit(1);
+    };
+
+    match AocClient::from_env_with(session).whoami(year) {
+        Ok(Some(user)) => println!("Logged in as {user}."),
+        Ok(None) => {
+            eprintln!("The session cookie is expired. Run \"cargo aoc-login\" to log in again.");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to check session cookie: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+/// Module that reads and appends JSON Lines files, i.e. files that hold one JSON record per line.
+/// Used for records that only ever grow, like submissions and benchmark runs.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(s) => write!(f, "could not parse record: {s}"),
+            Error::IO(e) => write!(f, "could not access records: {e}"),
+        }
+    }
+}
+
+/// Parses every non-empty line as a record.
+pub fn parse<T: DeserializeOwned>(content: &str) -> Result<Vec<T>, Error> {
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| serde_json::from_str(l).map_err(|e| Error::Parser(format!("\"{l}\": {e}"))))
+        .collect()
+}
+
+/// Reads all records of a file. A missing file has no records.
+pub fn read<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>, Error> {
+    match fs::read_to_string(path) {
+        Ok(content) => parse(&content),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Appends a record to a file, creating the file and its directory if needed.
+pub fn append<T: Serialize>(path: &Path, record: &T) -> Result<(), Error> {
+    let json = serde_json::to_string(record).map_err(|e| Error::Parser(e.to_string()))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{json}")?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{append, parse, read};
+
+    #[test]
+    fn appends_and_reads_records() {
+        let dir = env::temp_dir().join(format!("aoc-jsonl-{}", process::id()));
+        // the directory is created as needed.
+        let path = dir.join("nested").join("records.jsonl");
+
+        assert!(read::<u32>(&path).unwrap().is_empty());
+
+        append(&path, &1).unwrap();
+        append(&path, &2).unwrap();
+        let records: Vec<u32> = read(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(records, [1, 2]);
+    }
+
+    #[test]
+    fn skips_empty_lines() {
+        assert_eq!(parse::<u32>("1\n\n2\n").unwrap(), [1, 2]);
+        assert!(parse::<u32>("1\nPart 1: 2\n").is_err());
+    }
+}
@@ -1,36 +1,179 @@
-use crate::Day;
-use std::{env, fs};
+use crate::{Day, Part, Puzzle};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
 
+pub mod answers;
 pub mod aoc_cli;
+pub mod aoc_client;
+pub mod backend;
+pub mod benchmark_history;
+pub mod clock;
 pub mod commands;
+pub mod jsonl;
+pub mod puzzle;
 pub mod readme_benchmarks;
+pub mod registry;
 pub mod runner;
+pub mod session;
+pub mod submissions;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Returns the directory that contains the data of an event, e.g. `data/2023`.
+#[must_use]
+pub fn get_data_dir(year: u16) -> PathBuf {
+    PathBuf::from("data").join(year.to_string())
+}
+
+/// Returns the path of a data file of a puzzle, e.g. `data/2023/inputs/08.txt`.
+#[must_use]
+pub fn get_data_path(folder: &str, puzzle: Puzzle, extension: &str) -> PathBuf {
+    get_data_dir(puzzle.year)
+        .join(folder)
+        .join(format!("{}.{extension}", puzzle.day))
+}
+
+/// Returns the path of the solution of a puzzle, e.g. `src/bin/2023-08.rs`.
+#[must_use]
+pub fn get_bin_path(puzzle: Puzzle) -> PathBuf {
+    PathBuf::from("src/bin").join(format!("{puzzle}.rs"))
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: Puzzle) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(get_data_path(folder, puzzle, "txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads an example for a part of a puzzle.
+/// Examples are looked up in `data/YYYY/examples`, from most to least specific:
+/// `DD-P-name.txt` and `DD-name.txt` for a named example, `DD-P.txt` and `DD.txt` otherwise.
+#[must_use]
+pub fn read_example(puzzle: Puzzle, part: Part, name: Option<&str>) -> String {
+    let cwd = env::current_dir().unwrap();
+    let dir = cwd.join(get_data_dir(puzzle.year)).join("examples");
+    let filepath = find_example(&dir, puzzle.day, part, name);
+    let f = fs::read_to_string(filepath);
+    f.expect("could not open example file")
+}
+
+/// Returns the most specific example file that exists, or the least specific one if none does.
+fn find_example(dir: &Path, day: Day, part: Part, name: Option<&str>) -> PathBuf {
+    let names = match name {
+        Some(name) => [
+            format!("{day}-{part}-{name}.txt"),
+            format!("{day}-{name}.txt"),
+        ],
+        None => [format!("{day}-{part}.txt"), format!("{day}.txt")],
+    };
+
+    names
+        .iter()
+        .map(|x| dir.join(x))
+        .find(|x| x.exists())
+        .unwrap_or_else(|| dir.join(&names[1]))
+}
+
+/// Creates the constants `PUZZLE` and `DAY` and sets up the input and runner for each part.
+/// The arguments of the binary are parsed once into a [`RunnerConfig`](crate::template::runner::RunnerConfig).
+/// Days with a single part, e.g. the last day of an event, pass `single_part` and only define `part_one`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        /// The current day.
-        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+    ($year:expr, $day:expr) => {
+        advent_of_code::solution!(
+            @define $year,
+            $day,
+            Some(|input| part_two(input).map(|x| x.to_string()))
+        );
 
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            let config = RunnerConfig::from_env();
+            let input = config.read_input(PUZZLE);
+            run_part(part_one, &input, PUZZLE, advent_of_code::Part::One, &config);
+            run_part(part_two, &input, PUZZLE, advent_of_code::Part::Two, &config);
         }
     };
+    ($year:expr, $day:expr, single_part) => {
+        advent_of_code::solution!(@define $year, $day, None);
+
+        fn main() {
+            use advent_of_code::template::runner::*;
+            let config = RunnerConfig::from_env();
+            let input = config.read_input(PUZZLE);
+            run_part(part_one, &input, PUZZLE, advent_of_code::Part::One, &config);
+            skip_part(PUZZLE, advent_of_code::Part::Two, &config);
+        }
+    };
+    (@define $year:expr, $day:expr, $part_two:expr) => {
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::Puzzle =
+            advent_of_code::Puzzle::new($year, advent_of_code::day!($day));
+
+        /// The current day.
+        #[allow(dead_code)]
+        const DAY: advent_of_code::Day = PUZZLE.day;
+
+        /// This solution, as registered in [`advent_of_code::template::registry`].
+        #[allow(dead_code)]
+        pub const SOLUTION: advent_of_code::template::registry::Solution =
+            advent_of_code::template::registry::Solution {
+                puzzle: PUZZLE,
+                part_one: |input| part_one(input).map(|x| x.to_string()),
+                part_two: $part_two,
+            };
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::find_example;
+    use crate::{day, Part};
+
+    #[test]
+    fn finds_most_specific_example() {
+        let dir = env::temp_dir().join(format!("aoc-examples-dir-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        // missing files resolve to the shared example.
+        assert_eq!(
+            find_example(&dir, day!(8), Part::Two, None),
+            dir.join("08.txt")
+        );
+        assert_eq!(
+            find_example(&dir, day!(8), Part::Two, Some("loop")),
+            dir.join("08-loop.txt")
+        );
+
+        for name in ["08.txt", "08-2.txt", "08-loop.txt", "08-1-loop.txt"] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let found = [
+            find_example(&dir, day!(8), Part::One, None),
+            find_example(&dir, day!(8), Part::Two, None),
+            find_example(&dir, day!(8), Part::One, Some("loop")),
+            find_example(&dir, day!(8), Part::Two, Some("loop")),
+        ];
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            found,
+            [
+                dir.join("08.txt"),
+                dir.join("08-2.txt"),
+                dir.join("08-1-loop.txt"),
+                dir.join("08-loop.txt"),
+            ]
+        );
+    }
 }
@@ -0,0 +1,146 @@
+/// Module that extracts examples and expected answers from puzzle descriptions.
+/// Descriptions are the markdown files in `data/YYYY/puzzles`, as written by `cargo download` or aoc-cli.
+use crate::Answer;
+
+/// Splits a description into the text of part one and, once unlocked, part two.
+pub fn split_parts(markdown: &str) -> (&str, Option<&str>) {
+    match markdown.find("Part Two ---") {
+        Some(index) => {
+            // split at the start of the heading line.
+            let start = markdown[..index].rfind('\n').map_or(0, |x| x + 1);
+            (&markdown[..start], Some(&markdown[start..]))
+        }
+        None => (markdown, None),
+    }
+}
+
+/// Returns the contents of the fenced code blocks in `markdown`, in order.
+pub fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut current: Option<String> = None;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            match current.take() {
+                Some(block) => blocks.push(block),
+                None => current = Some(String::new()),
+            }
+        } else if let Some(block) = current.as_mut() {
+            block.push_str(line);
+            block.push('\n');
+        }
+    }
+
+    blocks
+}
+
+/// Returns the emphasized inline code values in `markdown`, in order.
+/// The puzzles emphasize the answers to their examples this way, e.g. `*`142`*`.
+pub fn emphasized_values(markdown: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut rest = markdown;
+
+    while let Some(start) = rest.find("*`").into_iter().chain(rest.find("`*")).min() {
+        let (open, close) = if rest[start..].starts_with("*`") {
+            ("*`", "`*")
+        } else {
+            ("`*", "*`")
+        };
+
+        rest = &rest[start + open.len()..];
+
+        let Some(end) = rest.find(close) else {
+            break;
+        };
+
+        let value = &rest[..end];
+        if !value.is_empty() && !value.contains(char::is_whitespace) && !value.contains('`') {
+            values.push(value.to_string());
+            rest = &rest[end + close.len()..];
+        }
+    }
+
+    values
+}
+
+/// Returns the answers the page confirms for solved parts, in order of the parts.
+pub fn confirmed_answers(markdown: &str) -> Vec<Answer> {
+    let prefix = "Your puzzle answer was";
+
+    markdown
+        .match_indices(prefix)
+        .filter_map(|(index, _)| {
+            let rest = markdown[index + prefix.len()..].trim_start();
+            let rest = rest.strip_prefix('`')?;
+            let end = rest.find('`')?;
+            Some(Answer::from(&rest[..end]))
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{code_blocks, confirmed_answers, emphasized_values, split_parts};
+    use crate::Answer;
+
+    static PUZZLE: &str = "## --- Day 1: Trebuchet?! ---
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the calibration values are `12` and `38`. Adding these together produces *`50`*.
+
+Your puzzle answer was `54388`.
+
+## --- Part Two ---
+
+For example:
+
+```
+two1nine
+```
+
+Adding these together produces `*29*`.
+";
+
+    #[test]
+    fn splits_parts() {
+        let (part_one, part_two) = split_parts(PUZZLE);
+        assert!(part_one.ends_with("`54388`.\n\n"));
+        assert!(part_two.unwrap().starts_with("## --- Part Two ---"));
+
+        assert_eq!(
+            split_parts("## --- Day 1 ---\n"),
+            ("## --- Day 1 ---\n", None)
+        );
+    }
+
+    #[test]
+    fn finds_code_blocks() {
+        assert_eq!(code_blocks(PUZZLE), ["1abc2\npqr3stu8vwx\n", "two1nine\n"]);
+        assert!(code_blocks("no blocks, only `code`").is_empty());
+    }
+
+    #[test]
+    fn finds_emphasized_values() {
+        assert_eq!(emphasized_values(PUZZLE), ["50", "29"]);
+        assert_eq!(
+            emphasized_values("*`1`* and *not code* and *`two words`* and *`6,9`*"),
+            ["1", "6,9"]
+        );
+    }
+
+    #[test]
+    fn finds_confirmed_answers() {
+        assert_eq!(confirmed_answers(PUZZLE), [Answer::Integer(54388)]);
+        assert_eq!(
+            confirmed_answers("Your puzzle answer was `1`.\n\nYour puzzle answer was `ABC`."),
+            [Answer::Integer(1), Answer::Text("ABC".into())]
+        );
+        assert!(confirmed_answers("## --- Day 1 ---\n").is_empty());
+    }
+}
@@ -1,8 +1,11 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+/// Each event can have its own table, marked with `<!--- benchmarking table YYYY --->`.
+/// Otherwise, the table marked with `<!--- benchmarking table --->` is used.
+use std::{fs, io, time::Duration};
 
-use crate::Day;
+use crate::template::get_bin_path;
+use crate::{Day, Puzzle};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -18,12 +21,21 @@ impl From<std::io::Error> for Error {
     }
 }
 
+/// The timing of a single solution part, `spread` is the standard deviation if the part was benched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timing {
+    pub duration: Duration,
+    pub spread: Option<Duration>,
+}
+
+/// The timings of a day, `single_part` is set if the puzzle has no part two.
 #[derive(Clone)]
 pub struct Timings {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
-    pub total_nanos: f64,
+    pub part_1: Option<Timing>,
+    pub part_2: Option<Timing>,
+    pub single_part: bool,
+    pub total: Duration,
 }
 
 pub struct TablePosition {
@@ -31,13 +43,22 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+fn get_marker(year: u16) -> String {
+    format!("<!--- benchmarking table {year} --->")
+}
+
+/// Returns the marker of the table of an event, falling back to the shared table.
+fn find_marker(readme: &str, year: u16) -> String {
+    let marker = get_marker(year);
+    if readme.contains(&marker) {
+        marker
+    } else {
+        MARKER.into()
+    }
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -58,11 +79,17 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(
+    prefix: &str,
+    marker: &str,
+    year: u16,
+    timings: Vec<Timings>,
+    total_millis: f64,
+) -> String {
+    let header = format!("{prefix} {year} Benchmarks");
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker.into(),
         header,
         String::new(),
         "| Day | Part 1 | Part 2 |".into(),
@@ -70,62 +97,109 @@ fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> St
     ];
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
+        let path = get_bin_path(Puzzle::new(year, timing.day));
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}](./{}) | `{}` | `{}` |",
             timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            path.display(),
+            format_timing(timing.part_1),
+            if timing.single_part {
+                "N/A".into()
+            } else {
+                format_timing(timing.part_2)
+            }
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn format_timing(timing: Option<Timing>) -> String {
+    match timing {
+        Some(Timing {
+            duration,
+            spread: Some(spread),
+        }) => format!("{duration:.1?} ± {spread:.1?}"),
+        Some(Timing {
+            duration,
+            spread: None,
+        }) => format!("{duration:.1?}"),
+        None => "-".into(),
+    }
+}
+
+fn update_content(
+    s: &mut String,
+    year: u16,
+    timings: Vec<Timings>,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let marker = find_marker(s, year);
+    let positions = locate_table(s, &marker)?;
+    let table = construct_table("##", &marker, year, timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+pub fn update(year: u16, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
+    use std::time::Duration;
+
+    use super::{get_marker, update_content, Timing, Timings, MARKER};
     use crate::day;
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
                 day: day!(1),
-                part_1: Some("10ms".into()),
-                part_2: Some("20ms".into()),
-                total_nanos: 3e+10,
+                part_1: Some(Timing {
+                    duration: Duration::from_millis(10),
+                    spread: None,
+                }),
+                part_2: Some(Timing {
+                    duration: Duration::from_millis(20),
+                    spread: None,
+                }),
+                single_part: false,
+                total: Duration::from_millis(30),
             },
             Timings {
                 day: day!(2),
-                part_1: Some("30ms".into()),
-                part_2: Some("40ms".into()),
-                total_nanos: 7e+10,
+                part_1: Some(Timing {
+                    duration: Duration::from_millis(30),
+                    spread: None,
+                }),
+                part_2: Some(Timing {
+                    duration: Duration::from_millis(40),
+                    spread: None,
+                }),
+                single_part: false,
+                total: Duration::from_millis(70),
             },
             Timings {
                 day: day!(4),
-                part_1: Some("40ms".into()),
-                part_2: Some("50ms".into()),
-                total_nanos: 9e+10,
+                part_1: Some(Timing {
+                    duration: Duration::from_millis(40),
+                    spread: Some(Duration::from_micros(1500)),
+                }),
+                part_2: Some(Timing {
+                    duration: Duration::from_millis(50),
+                    spread: None,
+                }),
+                single_part: false,
+                total: Duration::from_millis(90),
             },
         ]
     }
@@ -134,47 +208,50 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, 2023, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, 2023, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, 2023, get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.contains("## 2023 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, 2023, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, 2023, get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        assert_eq!(
+            s.matches("## 2023 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
     }
 
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, 2023, get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "## 2023 Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2023-01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2023-02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | `40.0ms ± 1.5ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +260,34 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn updates_table_of_year() {
+        let shared = format!("{MARKER}{MARKER}");
+        let mut s = format!("{shared}\n{}{}", get_marker(2022), get_marker(2022));
+        update_content(&mut s, 2022, get_mock_timings(), 190.0).unwrap();
+
+        let (shared_table, table) = s.split_once('\n').unwrap();
+        assert_eq!(shared_table, shared);
+        assert!(table.starts_with(&format!("{}\n## 2022 Benchmarks", get_marker(2022))));
+        assert!(table.contains("| [Day 1](./src/bin/2022-01.rs)"));
+        assert!(table.ends_with(&get_marker(2022)));
+    }
+
+    #[test]
+    fn formats_single_part_days() {
+        let mut s = format!("{MARKER}{MARKER}");
+        let timings = vec![Timings {
+            day: day!(25),
+            part_1: Some(Timing {
+                duration: Duration::from_millis(10),
+                spread: None,
+            }),
+            part_2: None,
+            single_part: true,
+            total: Duration::from_millis(10),
+        }];
+        update_content(&mut s, 2023, timings, 10.0).unwrap();
+        assert!(s.contains("| [Day 25](./src/bin/2023-25.rs) | `10.0ms` | `N/A` |"));
+    }
 }
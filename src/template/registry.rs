@@ -0,0 +1,86 @@
+/// Registry of all solutions in `src/bin`, of every year, so one process can run any day without spawning cargo.
+/// With the `registry` feature, every solution binary is also compiled into the library as a module (see `build.rs`)
+/// and registered by the `solution!` macro. The registry is empty without the feature and when the library itself is
+/// compiled for tests.
+use crate::{Part, Puzzle};
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+/// The parts of a solution, with their answers converted to strings.
+/// `part_two` is [`None`] for days with a single part.
+pub struct Solution {
+    pub puzzle: Puzzle,
+    pub part_one: fn(&str) -> Option<String>,
+    pub part_two: Option<fn(&str) -> Option<String>>,
+}
+
+impl Solution {
+    /// Whether the puzzle of this solution has the given part.
+    pub fn has_part(&self, part: Part) -> bool {
+        match part {
+            Part::One => true,
+            Part::Two => self.part_two.is_some(),
+        }
+    }
+
+    /// Runs the given part of the solution, returns [`None`] if the puzzle does not have the part.
+    pub fn run(&self, part: Part, input: &str) -> Option<String> {
+        match part {
+            Part::One => (self.part_one)(input),
+            Part::Two => self.part_two.and_then(|x| x(input)),
+        }
+    }
+}
+
+/// Every registered solution, ordered by year and day.
+pub fn solutions() -> &'static [Solution] {
+    generated::SOLUTIONS
+}
+
+/// The registered solution for a puzzle, if there is one.
+pub fn find(puzzle: Puzzle) -> Option<&'static Solution> {
+    find_in(solutions(), puzzle)
+}
+
+fn find_in(solutions: &[Solution], puzzle: Puzzle) -> Option<&Solution> {
+    solutions.iter().find(|x| x.puzzle == puzzle)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{find_in, Solution};
+    use crate::{day, Part, Puzzle};
+
+    static SOLUTIONS: &[Solution] = &[
+        Solution {
+            puzzle: Puzzle::new(2023, day!(1)),
+            part_one: |input| Some(input.len().to_string()),
+            part_two: None,
+        },
+        Solution {
+            puzzle: Puzzle::new(2023, day!(3)),
+            part_one: |_| None,
+            part_two: Some(|input| Some(input.to_uppercase())),
+        },
+    ];
+
+    #[test]
+    fn finds_solution_by_puzzle() {
+        let puzzle = Puzzle::new(2023, day!(3));
+        assert_eq!(find_in(SOLUTIONS, puzzle).unwrap().puzzle, puzzle);
+        assert!(find_in(SOLUTIONS, Puzzle::new(2023, day!(2))).is_none());
+        assert!(find_in(SOLUTIONS, Puzzle::new(2022, day!(3))).is_none());
+    }
+
+    #[test]
+    fn runs_parts() {
+        let solution = find_in(SOLUTIONS, Puzzle::new(2023, day!(1))).unwrap();
+        assert_eq!(solution.run(Part::One, "abc"), Some("3".into()));
+        assert_eq!(solution.run(Part::Two, "abc"), None);
+        assert!(!solution.has_part(Part::Two));
+
+        let solution = find_in(SOLUTIONS, Puzzle::new(2023, day!(3))).unwrap();
+        assert_eq!(solution.run(Part::Two, "abc"), Some("ABC".into()));
+        assert!(solution.has_part(Part::Two));
+    }
+}
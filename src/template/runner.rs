@@ -1,104 +1,586 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::{
+    answers, backend,
+    clock::{format_remaining, SystemClock},
+    get_bin_path, get_data_path, jsonl, read_file, submissions, ANSI_ITALIC, ANSI_RESET,
+};
+use crate::{Answer, Day, Part, Puzzle};
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::error::Error;
+use std::ffi::OsString;
 use std::fmt::Display;
+use std::fs;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// How the result of a solution part is reported on stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Human-readable, ANSI-decorated lines (e.g. `Part 1: 42 (1.2ms)`).
+    #[default]
+    Text,
+    /// One [`PartResult`] JSON record per line.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text => f.write_str("text"),
+            Self::Json => f.write_str("json"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl std::error::Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting an output format of either \"text\" or \"json\"")
+    }
+}
+
+/// Environment variable that points a solution binary to the file it should report its [`PartResult`]s to.
+/// Set by the `all` command when invoking solutions so results don't have to be parsed from stdout.
+pub const RESULTS_FILE_ENV: &str = "AOC_RESULTS_FILE";
+
+/// Settings that control how a solution part is benchmarked when running with `--time`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Number of untimed runs before sampling starts.
+    pub warmup: u32,
+    /// Approximate time spent taking samples.
+    pub budget: Duration,
+    pub min_samples: u32,
+    pub max_samples: u32,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            budget: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10000,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Command-line arguments that mirror this config to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        vec![
+            "--warmup".into(),
+            self.warmup.to_string(),
+            "--budget-ms".into(),
+            self.budget.as_millis().to_string(),
+            "--min-samples".into(),
+            self.min_samples.to_string(),
+            "--max-samples".into(),
+            self.max_samples.to_string(),
+        ]
+    }
+
+    /// Takes the benchmark settings out of `args`, using the defaults for settings that are not passed.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, Box<dyn Error>> {
+        let default = Self::default();
+
+        let bench = Self {
+            warmup: args
+                .opt_value_from_str("--warmup")?
+                .unwrap_or(default.warmup),
+            budget: args
+                .opt_value_from_str("--budget-ms")?
+                .map_or(default.budget, Duration::from_millis),
+            min_samples: args
+                .opt_value_from_str("--min-samples")?
+                .unwrap_or(default.min_samples),
+            max_samples: args
+                .opt_value_from_str("--max-samples")?
+                .unwrap_or(default.max_samples),
+        };
+
+        if bench.min_samples > bench.max_samples {
+            return Err("--min-samples must not be greater than --max-samples".into());
+        }
+
+        Ok(bench)
+    }
+}
+
+/// Settings of a solution binary, parsed once from its command-line arguments by the [`solution!`](crate::solution) macro.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RunnerConfig {
+    /// Whether parts are benched according to `bench` (`--time`).
+    pub time: bool,
+    pub bench: BenchConfig,
+    /// The part to submit (`--submit <part>`).
+    pub submit: Option<Part>,
+    /// Whether to submit without checking earlier submissions (`--force`).
+    pub force: bool,
+    pub format: OutputFormat,
+    /// Only run this part (`--part <part>`).
+    pub part: Option<Part>,
+    /// Read the input from this file instead of `data/YYYY/inputs` (`--input <path>`).
+    pub input: Option<PathBuf>,
+}
+
+impl RunnerConfig {
+    /// Parses the arguments of the current process, exits if they are invalid.
+    pub fn from_env() -> Self {
+        Self::from_args(env::args_os().skip(1).collect()).unwrap_or_else(|e| {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        })
+    }
+
+    /// Parses the arguments of a solution binary, without the program name.
+    pub fn from_args(args: Vec<OsString>) -> Result<Self, Box<dyn Error>> {
+        let mut args = pico_args::Arguments::from_vec(args);
+        let config = Self::parse(&mut args)?;
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+        }
+
+        Ok(config)
+    }
+
+    /// Takes the runner settings out of `args`, leaving any other arguments.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, Box<dyn Error>> {
+        let config = Self {
+            time: args.contains("--time"),
+            bench: BenchConfig::parse(args)?,
+            submit: args.opt_value_from_str("--submit")?,
+            force: args.contains("--force"),
+            format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            part: args.opt_value_from_str("--part")?,
+            input: args
+                .opt_value_from_os_str("--input", |x| Ok::<_, Infallible>(PathBuf::from(x)))?,
+        };
+
+        if config.submit.is_some() && config.input.is_some() {
+            return Err("--submit can not be combined with --input".into());
+        }
+
+        if let (Some(submit), Some(part)) = (config.submit, config.part) {
+            if submit != part {
+                return Err(format!("--submit {submit} requires running part {submit}").into());
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Command-line arguments that mirror this config to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if self.time {
+            args.push("--time".into());
+            args.append(&mut self.bench.to_args());
+        }
+
+        if let Some(submit) = self.submit {
+            args.push("--submit".into());
+            args.push(submit.to_string());
+        }
+
+        if self.force {
+            args.push("--force".into());
+        }
+
+        if self.format != OutputFormat::Text {
+            args.push("--format".into());
+            args.push(self.format.to_string());
+        }
+
+        if let Some(part) = self.part {
+            args.push("--part".into());
+            args.push(part.to_string());
+        }
+
+        if let Some(input) = &self.input {
+            args.push("--input".into());
+            args.push(input.display().to_string());
+        }
+
+        args
+    }
+
+    /// Whether `part` is run, i.e. it is not excluded with `--part`.
+    pub fn runs(&self, part: Part) -> bool {
+        self.part.is_none_or(|x| x == part)
+    }
+
+    /// Reads the input of `puzzle`, or the file passed with `--input`.
+    pub fn read_input(&self, puzzle: Puzzle) -> String {
+        match &self.input {
+            Some(path) => fs::read_to_string(path).unwrap_or_else(|e| {
+                eprintln!("Failed to read input file \"{}\": {e}", path.display());
+                process::exit(1);
+            }),
+            None => read_file("inputs", puzzle),
+        }
+    }
+}
+
+/// Summary statistics of a benchmark run.
+/// Outliers (samples outside of 1.5 times the interquartile range) are excluded from all values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchStats {
+    #[serde(rename = "min_nanos", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_nanos", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "p95_nanos", with = "nanos")]
+    pub p95: Duration,
+    #[serde(rename = "mean_nanos", with = "nanos")]
+    pub mean: Duration,
+    #[serde(rename = "std_dev_nanos", with = "nanos")]
+    pub std_dev: Duration,
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    /// Computes statistics for a set of samples, returns [`None`] if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let q1 = percentile(&sorted, 0.25)?;
+        let q3 = percentile(&sorted, 0.75)?;
+        let fence = (q3 - q1) * 3 / 2;
+        let (low, high) = (q1.saturating_sub(fence), q3 + fence);
+
+        let kept: Vec<Duration> = sorted
+            .iter()
+            .copied()
+            .filter(|x| (low..=high).contains(x))
+            .collect();
+
+        let mean_secs = kept.iter().map(Duration::as_secs_f64).sum::<f64>() / kept.len() as f64;
+        let variance = kept
+            .iter()
+            .map(|x| (x.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>()
+            / kept.len() as f64;
+
+        Some(Self {
+            min: *kept.first()?,
+            median: median(&kept)?,
+            p95: percentile(&kept, 0.95)?,
+            mean: Duration::from_secs_f64(mean_secs),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+            outliers: sorted.len() - kept.len(),
+        })
+    }
+}
+
+/// Nearest-rank percentile of an already sorted slice.
+fn percentile(sorted: &[Duration], p: f64) -> Option<Duration> {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted.get(rank.saturating_sub(1)).copied()
+}
+
+fn median(sorted: &[Duration]) -> Option<Duration> {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        Some((*sorted.get(mid - 1)? + *sorted.get(mid)?) / 2)
+    } else {
+        sorted.get(mid).copied()
+    }
+}
+
+/// (De)serializes a [`Duration`] as an integer amount of nanoseconds.
+pub(crate) mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u128(duration.as_nanos())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        from_nanos(u128::deserialize(deserializer)?)
+    }
+
+    fn from_nanos<E: serde::de::Error>(nanos: u128) -> Result<Duration, E> {
+        let secs = u64::try_from(nanos / 1_000_000_000).map_err(E::custom)?;
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+    }
+
+    /// (De)serializes an optional [`Duration`] as an optional integer amount of nanoseconds.
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+        use std::time::Duration;
+
+        pub fn serialize<S: Serializer>(
+            duration: &Option<Duration>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match duration {
+                Some(duration) => serializer.serialize_some(&duration.as_nanos()),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Duration>, D::Error> {
+            Option::<u128>::deserialize(deserializer)?
+                .map(super::from_nanos)
+                .transpose()
+        }
+    }
+}
+
+/// The machine-readable record emitted for a solution part when running with `--format json`.
+/// `duration_nanos` is the median execution time if the part was benched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: Day,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub solved: bool,
+    #[serde(rename = "duration_nanos", with = "nanos")]
+    pub duration: Duration,
+    pub samples: u128,
+    pub stats: Option<BenchStats>,
+    /// `false` if the puzzle does not have this part, e.g. part two of the last day.
+    #[serde(default = "is_applicable_by_default")]
+    pub applicable: bool,
+    /// Whether the answer matches the confirmed answer in `data/YYYY/answers`, [`None`] if there is none.
+    #[serde(default)]
+    pub verified: Option<bool>,
+}
+
+fn is_applicable_by_default() -> bool {
+    true
+}
+
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: Puzzle,
+    part: Part,
+    config: &RunnerConfig,
+) {
+    if !config.runs(part) {
+        return;
+    }
+
+    let format = config.format;
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, duration, samples, stats) = run_timed(func, input, config, |result| {
+        if format == OutputFormat::Text {
+            print_result(result, &part_str, "", "");
+        }
+    });
+
+    let answer = result.as_ref().map(Answer::from_result);
+    // stored answers belong to the puzzle input, not to an input passed with `--input`.
+    let verification = if config.input.is_some() {
+        answers::Verification::Unknown
+    } else {
+        verify_result(puzzle, part, answer.as_ref())
+    };
+
+    let record = PartResult {
+        year: puzzle.year,
+        day: puzzle.day,
+        part,
+        answer: answer.clone(),
+        solved: result.is_some(),
+        duration,
+        samples,
+        stats,
+        applicable: true,
+        verified: verification.is_correct(),
+    };
+
+    match format {
+        OutputFormat::Text => {
+            print_result(
+                &result,
+                &part_str,
+                &verification.marker(),
+                &format_duration(&duration, samples, stats.as_ref()),
+            );
+        }
+        OutputFormat::Json => print_record(&record),
+    }
+
+    report_record(&record);
+
+    if let Some(answer) = answer {
+        submit_result(&answer, puzzle, part, config);
+    }
+}
+
+/// Reports a part the puzzle does not have, e.g. part two of the last day, as not applicable.
+pub fn skip_part(puzzle: Puzzle, part: Part, config: &RunnerConfig) {
+    if !config.runs(part) {
+        return;
+    }
+
+    let record = PartResult {
+        year: puzzle.year,
+        day: puzzle.day,
+        part,
+        answer: None,
+        solved: false,
+        duration: Duration::ZERO,
+        samples: 0,
+        stats: None,
+        applicable: false,
+        verified: None,
+    };
+
+    match config.format {
+        OutputFormat::Text => println!("Part {part}: {ANSI_ITALIC}N/A{ANSI_RESET}"),
+        OutputFormat::Json => print_record(&record),
+    }
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    report_record(&record);
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if config.submit == Some(part) {
+        eprintln!("Day {} has no part {part} to submit.", puzzle.day);
+        process::exit(1);
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. in release, the function is benched according to the [`BenchConfig`] passed on the command-line.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    config: &RunnerConfig,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, u128, Option<BenchStats>) {
     let timer = Instant::now();
     let result = func(input.clone());
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
-    };
+    if !config.time {
+        return (result, base_time, 1, None);
+    }
+
+    let timers = bench(func, input, &base_time, &config.bench, config.format);
 
-    (result, run.0, run.1)
+    match BenchStats::from_samples(&timers) {
+        Some(stats) => (result, stats.median, timers.len() as u128, Some(stats)),
+        None => (result, base_time, 1, None),
+    }
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Runs the warmup and collects samples until the time budget is used up, bounded by the min. / max. sample count.
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+    format: OutputFormat,
+) -> Vec<Duration> {
+    if format == OutputFormat::Text {
+        let mut stdout = stdout();
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout.flush();
+    }
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    let mut estimate = *base_time;
+    let warmup_timer = Instant::now();
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
+    for i in 1..=config.warmup {
+        // need a clone here to make the borrow checker happy.
+        let cloned = input.clone();
+        func(cloned);
+        estimate = warmup_timer.elapsed() / i;
+
+        // don't let slow solutions spend more than a fraction of the budget on warming up.
+        if warmup_timer.elapsed() > config.budget / 10 {
+            break;
+        }
+    }
+
+    let bench_iterations = (config.budget.as_nanos() / cmp::max(estimate.as_nanos(), 10)).clamp(
+        u128::from(config.min_samples),
+        u128::from(cmp::max(config.min_samples, config.max_samples)),
     );
 
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
-        // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
         func(cloned);
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    timers
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_duration(duration: &Duration, samples: u128, stats: Option<&BenchStats>) -> String {
+    match stats {
+        Some(stats) => format!(
+            " ({duration:.1?} ± {:.1?}, min {:.1?}, p95 {:.1?} @ {samples} samples)",
+            stats.std_dev, stats.min, stats.p95
+        ),
+        None => format!(" ({duration:.1?})"),
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+fn verify_result(puzzle: Puzzle, part: Part, answer: Option<&Answer>) -> answers::Verification {
+    match answers::read(puzzle) {
+        Ok(answers) => answers.verify(part, answer),
+        Err(e) => {
+            eprintln!("Failed to read answers for {puzzle}: {e}");
+            answers::Verification::Unknown
+        }
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result<T: Display>(result: &Option<T>, part: &str, marker: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
         Some(result) => {
             if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
+                let str = format!("{part}: ▼{marker} {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -107,7 +589,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                     println!("{result}");
                 }
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
+                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{marker}{duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -121,47 +603,241 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 print!("{part}: ✖");
             } else {
                 print!("\r");
-                println!("{part}: ✖             ");
+                println!("{part}: ✖{marker}             ");
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+pub(crate) fn print_record(record: &PartResult) {
+    match serde_json::to_string(record) {
+        Ok(json) => println!("{json}"),
+        Err(e) => eprintln!("Failed to serialize result: {e}"),
+    }
+}
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+/// Append the record to the results file requested by a parent process, if any.
+fn report_record(record: &PartResult) {
+    let Ok(path) = env::var(RESULTS_FILE_ENV) else {
+        return;
+    };
+
+    if let Err(e) = jsonl::append(Path::new(&path), record) {
+        eprintln!("Failed to report result to \"{path}\": {e}");
     }
+}
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+/// Try to submit the part of the solution passed with `--submit` if:
+///  1. we are in `--release` mode.
+///  2. the backend selected by `AOC_BACKEND` is usable.
+///  3. the answer was not submitted before and is within the bounds of earlier "too high" / "too low" verdicts, unless `--force` is passed.
+///
+/// If the answer is not correct, the process exits with the code of the verdict.
+fn submit_result(answer: &Answer, puzzle: Puzzle, part: Part, config: &RunnerConfig) {
+    if config.submit != Some(part) {
+        return;
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    if !config.force {
+        let submissions = submissions::read(puzzle.year).unwrap_or_else(|e| {
+            eprintln!("Failed to read previous submissions: {e}");
+            process::exit(1);
+        });
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+        if let Err(refusal) = submissions::check(&submissions, puzzle.day, part, answer) {
+            eprintln!("Refusing to submit \"{answer}\": {refusal} Pass --force to submit anyway.");
+            process::exit(1);
+        }
+    }
+
+    println!("Submitting result...");
+
+    // the answer may change if the solution or the input is edited while waiting for a rate limit.
+    let get_modified = || {
+        [get_bin_path(puzzle), get_data_path("inputs", puzzle, "txt")]
+            .map(|path| fs::metadata(path).and_then(|x| x.modified()).ok())
     };
+    let modified = get_modified();
+    let mut is_waiting = false;
 
-    if part_submit != part {
-        return None;
+    let outcome = backend::from_env()
+        .and_then(|backend| {
+            submissions::submit_with_retry(
+                backend.as_ref(),
+                &SystemClock,
+                puzzle,
+                part,
+                answer,
+                || get_modified() == modified,
+                |remaining| {
+                    is_waiting = true;
+                    print!(
+                        "\r⏳ Rate limited, submitting again in {}. Press Ctrl-C to cancel.   ",
+                        format_remaining(remaining)
+                    );
+                    let _ = stdout().flush();
+                },
+            )
+        })
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to submit result: {e}");
+            process::exit(1);
+        });
+
+    if is_waiting {
+        println!();
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let submissions::Outcome::Submitted { verdict, response } = outcome else {
+        eprintln!("The solution or its input changed while waiting, not submitting again.");
         process::exit(1);
+    };
+
+    print_verdict(&verdict, &response);
+
+    if verdict.is_checked() {
+        let submission = submissions::Submission::new(puzzle.day, part, answer, verdict);
+        if let Err(e) = submissions::append(puzzle.year, &submission) {
+            eprintln!("Failed to record submission: {e}");
+        }
+    }
+
+    if verdict != submissions::Verdict::Correct {
+        process::exit(verdict.exit_code());
     }
+}
+
+fn print_verdict(verdict: &submissions::Verdict, response: &str) {
+    use submissions::Verdict;
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    match verdict {
+        Verdict::Correct => println!("✓ That's the right answer!"),
+        Verdict::Incorrect => println!("✗ That's not the right answer."),
+        Verdict::TooHigh => println!("✗ That's not the right answer, it is too high."),
+        Verdict::TooLow => println!("✗ That's not the right answer, it is too low."),
+        Verdict::RateLimited { wait } => {
+            println!("⏳ You gave an answer too recently. Wait {wait:?} before trying again.");
+        }
+        Verdict::AlreadySolved => println!("This part is already solved."),
+        Verdict::Unknown => println!("Unrecognized response:\n{}", response.trim()),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use super::{BenchConfig, BenchStats, OutputFormat, RunnerConfig};
+    use crate::Part;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    fn parse_config(args: &[&str]) -> Result<RunnerConfig, Box<dyn std::error::Error>> {
+        RunnerConfig::from_args(args.iter().map(Into::into).collect())
+    }
+
+    #[test]
+    fn parses_runner_config() {
+        assert_eq!(parse_config(&[]).unwrap(), RunnerConfig::default());
+
+        let config = parse_config(&[
+            "--time",
+            "--budget-ms",
+            "500",
+            "--submit",
+            "2",
+            "--force",
+            "--format",
+            "json",
+            "--part",
+            "2",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            config,
+            RunnerConfig {
+                time: true,
+                bench: BenchConfig {
+                    budget: Duration::from_millis(500),
+                    ..BenchConfig::default()
+                },
+                submit: Some(Part::Two),
+                force: true,
+                format: OutputFormat::Json,
+                part: Some(Part::Two),
+                input: None,
+            }
+        );
+        assert!(config.runs(Part::Two));
+        assert!(!config.runs(Part::One));
+    }
+
+    #[test]
+    fn rejects_invalid_runner_config() {
+        // a missing value must not panic.
+        assert!(parse_config(&["--submit"]).is_err());
+        assert!(parse_config(&["--submit", "3"]).is_err());
+        assert!(parse_config(&["--format", "xml"]).is_err());
+        assert!(parse_config(&["--min-samples", "10", "--max-samples", "5"]).is_err());
+        assert!(parse_config(&["--submit", "1", "--input", "other.txt"]).is_err());
+        assert!(parse_config(&["--submit", "1", "--part", "2"]).is_err());
+    }
+
+    #[test]
+    fn mirrors_runner_config_to_args() {
+        let config = RunnerConfig {
+            time: true,
+            force: true,
+            format: OutputFormat::Json,
+            part: Some(Part::One),
+            input: Some(PathBuf::from("data/2023/examples/01.txt")),
+            ..RunnerConfig::default()
+        };
+
+        let args = config.to_args();
+        assert_eq!(
+            RunnerConfig::from_args(args.into_iter().map(Into::into).collect()).unwrap(),
+            config
+        );
+    }
+
+    #[test]
+    fn stats_of_empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn stats_of_uniform_samples() {
+        let stats = BenchStats::from_samples(&millis(&[5, 5, 5, 5])).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(5));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn stats_median_and_percentile() {
+        let samples = millis(&[
+            20, 1, 19, 2, 18, 3, 17, 4, 16, 5, 15, 6, 14, 7, 13, 8, 12, 9, 11, 10,
+        ]);
+        let stats = BenchStats::from_samples(&samples).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(10500));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        assert_eq!(stats.mean, Duration::from_micros(10500));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn stats_reject_outliers() {
+        let stats = BenchStats::from_samples(&millis(&[10, 11, 10, 12, 11, 10, 500])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.median, Duration::from_micros(10500));
+        assert_eq!(stats.p95, Duration::from_millis(12));
+    }
 }
@@ -0,0 +1,174 @@
+/// Module that manages the Advent of Code session cookie.
+/// The cookie is stored in `~/.adventofcode.session`, the same file aoc-cli reads.
+use std::{
+    env,
+    fmt::{Debug, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+static SESSION_ENV: &str = "ADVENT_OF_CODE_SESSION";
+static SESSION_FILE: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum Error {
+    /// The token is not a session cookie. Never contains the token.
+    InvalidFormat,
+    MissingHome,
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidFormat => write!(
+                f,
+                "this does not look like a session cookie. Copy the value of the \"session\" cookie from adventofcode.com."
+            ),
+            Error::MissingHome => write!(f, "could not find home directory."),
+            Error::IO(e) => write!(f, "could not access session file: {e}"),
+        }
+    }
+}
+
+/// A session cookie. Its `Debug` implementation does not reveal the token.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Session(String);
+
+impl Session {
+    /// Checks that `token` looks like a session cookie, i.e. a long hex string.
+    pub fn parse(token: &str) -> Result<Self, Error> {
+        let token = token.trim();
+        let token = token.strip_prefix("session=").unwrap_or(token);
+
+        if token.len() < 64 || !token.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Error::InvalidFormat);
+        }
+
+        Ok(Self(token.to_string()))
+    }
+
+    pub fn token(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Debug for Session {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Session(<redacted>)")
+    }
+}
+
+pub fn get_path() -> Result<PathBuf, Error> {
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .ok_or(Error::MissingHome)?;
+    Ok(PathBuf::from(home).join(SESSION_FILE))
+}
+
+/// Loads the session from `ADVENT_OF_CODE_SESSION` or the session file.
+pub fn load() -> Option<Session> {
+    let token = match env::var(SESSION_ENV) {
+        Ok(token) => token,
+        Err(_) => fs::read_to_string(get_path().ok()?).ok()?,
+    };
+    Session::parse(&token).ok()
+}
+
+/// Stores the session in the session file, readable by the current user only.
+pub fn store(session: &Session) -> Result<PathBuf, Error> {
+    let path = get_path()?;
+    write_private(&path, session.token())?;
+    Ok(path)
+}
+
+/// Removes the session file. Returns whether there was one.
+pub fn remove() -> Result<bool, Error> {
+    match fs::remove_file(get_path()?) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(unix)]
+fn write_private(path: &Path, content: &str) -> Result<(), Error> {
+    use std::{
+        io::Write,
+        os::unix::fs::{OpenOptionsExt, PermissionsExt},
+    };
+
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    // the mode only applies to new files.
+    file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    file.write_all(content.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn write_private(path: &Path, content: &str) -> Result<(), Error> {
+    fs::write(path, content)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{write_private, Error, Session};
+
+    static TOKEN: &str = "53616c7465645f5f0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn parses_tokens() {
+        assert_eq!(Session::parse(TOKEN).unwrap().token(), TOKEN);
+        assert_eq!(
+            Session::parse(&format!(" session={TOKEN}\n"))
+                .unwrap()
+                .token(),
+            TOKEN
+        );
+        assert!(matches!(Session::parse(""), Err(Error::InvalidFormat)));
+        assert!(matches!(
+            Session::parse("abc123"),
+            Err(Error::InvalidFormat)
+        ));
+        assert!(matches!(
+            Session::parse(&TOKEN.replace('a', "z")),
+            Err(Error::InvalidFormat)
+        ));
+    }
+
+    #[test]
+    fn does_not_reveal_token() {
+        let session = Session::parse(TOKEN).unwrap();
+        assert_eq!(format!("{session:?}"), "Session(<redacted>)");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn writes_private_file() {
+        use std::{env, fs, os::unix::fs::PermissionsExt, process};
+
+        let path = env::temp_dir().join(format!("aoc-session-{}", process::id()));
+        fs::write(&path, "old").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_private(&path, TOKEN).unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        let content = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(content, TOKEN);
+    }
+}
@@ -0,0 +1,475 @@
+/// Module that keeps a record of every submitted answer and its verdict.
+/// The record is used to refuse submissions that are known to be wrong before they reach Advent of Code.
+/// Each event has its own record in `data/YYYY/submissions.jsonl`.
+use std::{
+    fmt::Display,
+    io,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::backend::{self, AocBackend};
+use crate::template::clock::{wait_until, Clock};
+use crate::template::{get_data_dir, jsonl};
+use crate::{Answer, Day, Part, Puzzle};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<jsonl::Error> for Error {
+    fn from(e: jsonl::Error) -> Self {
+        match e {
+            jsonl::Error::Parser(s) => Error::Parser(s),
+            jsonl::Error::IO(e) => Error::IO(e),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(s) => write!(f, "could not parse submissions: {s}"),
+            Error::IO(e) => write!(f, "could not access submissions: {e}"),
+        }
+    }
+}
+
+/// The response of Advent of Code to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, the answer was not checked.
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part was solved before, the answer was not checked.
+    AlreadySolved,
+    /// The response could not be recognized.
+    Unknown,
+}
+
+impl Verdict {
+    /// Classifies the response to a submission.
+    pub fn from_response(response: &str) -> Self {
+        // responses may be wrapped to the terminal width.
+        let response = response.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if response.contains("That's the right answer") {
+            Verdict::Correct
+        } else if response.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if response.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if response.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if response.contains("You gave an answer too recently") {
+            Verdict::RateLimited {
+                wait: parse_wait(&response).unwrap_or_default(),
+            }
+        } else if response.contains("Did you already complete it?") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Whether the answer was checked by Advent of Code.
+    pub fn is_checked(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+
+    /// The exit code of `solve --submit` for this verdict.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Verdict::Correct => 0,
+            Verdict::Incorrect => 2,
+            Verdict::TooHigh => 3,
+            Verdict::TooLow => 4,
+            Verdict::RateLimited { .. } => 5,
+            Verdict::AlreadySolved => 6,
+            Verdict::Unknown => 7,
+        }
+    }
+}
+
+/// Parses the wait time of a rate-limited response, e.g. `You have 1m 30s left to wait.`
+fn parse_wait(response: &str) -> Option<Duration> {
+    let end = response.find(" left to wait")?;
+    let start = response[..end].rfind("You have ")? + "You have ".len();
+
+    response[start..end]
+        .split_whitespace()
+        .map(|x| {
+            let (value, unit) = x.split_at(x.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::RateLimited { wait } => write!(f, "rate limited for {wait:?}"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// A single submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: Day,
+    pub part: Part,
+    pub answer: Answer,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+impl Submission {
+    /// Creates a submission for the current time.
+    pub fn new(day: Day, part: Part, answer: &Answer, verdict: Verdict) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |x| x.as_secs());
+
+        Self {
+            day,
+            part,
+            answer: answer.clone(),
+            verdict,
+            timestamp,
+        }
+    }
+}
+
+/// The reason an answer is not submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The answer was submitted before.
+    Duplicate(Verdict),
+    /// An answer at or below this one was "too high".
+    TooHigh(Answer),
+    /// An answer at or above this one was "too low".
+    TooLow(Answer),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Duplicate(verdict) => {
+                write!(f, "this answer was submitted before ({verdict}).")
+            }
+            Refusal::TooHigh(bound) => write!(f, "\"{bound}\" was already too high."),
+            Refusal::TooLow(bound) => write!(f, "\"{bound}\" was already too low."),
+        }
+    }
+}
+
+/// Checks an answer against earlier submissions for the same part.
+/// Bounds only apply to answers that are integers.
+pub fn check(
+    submissions: &[Submission],
+    day: Day,
+    part: Part,
+    answer: &Answer,
+) -> Result<(), Refusal> {
+    let previous = submissions
+        .iter()
+        .filter(|x| x.day == day && x.part == part);
+
+    // answers that were not checked (e.g. because of a rate limit) may be submitted again.
+    if let Some(duplicate) = previous
+        .clone()
+        .find(|x| &x.answer == answer && x.verdict.is_checked())
+    {
+        return Err(Refusal::Duplicate(duplicate.verdict));
+    }
+
+    for submission in previous {
+        let Some(ordering) = answer.cmp_integer(&submission.answer) else {
+            continue;
+        };
+
+        match submission.verdict {
+            Verdict::TooHigh if ordering.is_ge() => {
+                return Err(Refusal::TooHigh(submission.answer.clone()));
+            }
+            Verdict::TooLow if ordering.is_le() => {
+                return Err(Refusal::TooLow(submission.answer.clone()));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// How often an answer is submitted before giving up on a rate limit.
+const MAX_ATTEMPTS: usize = 5;
+
+/// The outcome of [`submit_with_retry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The verdict of the last submission and the response it was parsed from.
+    Submitted { verdict: Verdict, response: String },
+    /// The answer may have changed while waiting, so it was not submitted again.
+    Stopped,
+}
+
+/// Submits an answer and submits it again once the wait is over if the submission was rate limited.
+/// While waiting, the countdown is reported to `on_wait`, see [`wait_until`].
+/// Before submitting again, the retry stops if `is_current` returns `false`.
+pub fn submit_with_retry(
+    backend: &dyn AocBackend,
+    clock: &dyn Clock,
+    puzzle: Puzzle,
+    part: Part,
+    answer: &Answer,
+    is_current: impl Fn() -> bool,
+    mut on_wait: impl FnMut(Duration),
+) -> Result<Outcome, backend::Error> {
+    let mut attempts = 0;
+
+    loop {
+        let response = backend.submit(puzzle, part, answer)?;
+        let verdict = Verdict::from_response(&response);
+        attempts += 1;
+
+        let Verdict::RateLimited { wait } = verdict else {
+            return Ok(Outcome::Submitted { verdict, response });
+        };
+
+        if attempts >= MAX_ATTEMPTS {
+            return Ok(Outcome::Submitted { verdict, response });
+        }
+
+        // the wait time is rounded down to seconds.
+        let deadline = clock.now() + wait + Duration::from_secs(1);
+
+        wait_until(clock, deadline, &mut on_wait);
+
+        if !is_current() {
+            return Ok(Outcome::Stopped);
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path(year: u16) -> PathBuf {
+    get_data_dir(year).join("submissions.jsonl")
+}
+
+/// Reads all recorded submissions of an event. A missing file means that nothing was submitted yet.
+pub fn read(year: u16) -> Result<Vec<Submission>, Error> {
+    Ok(jsonl::read(&get_path(year))?)
+}
+
+pub fn append(year: u16, submission: &Submission) -> Result<(), Error> {
+    Ok(jsonl::append(&get_path(year), submission)?)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{check, submit_with_retry, Outcome, Refusal, Submission, Verdict};
+    use crate::template::jsonl;
+    use crate::template::{backend::FakeBackend, clock::Clock, clock::FakeClock};
+    use crate::{day, Answer, Part, Puzzle};
+
+    static RATE_LIMITED: &str = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.";
+
+    fn submission(part: Part, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            day: day!(1),
+            part,
+            answer: Answer::from(answer),
+            verdict,
+            timestamp: 1_701_388_800,
+        }
+    }
+
+    #[test]
+    fn classifies_responses() {
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Verdict::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Verdict::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck, ...",
+                Verdict::Incorrect,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m\n 5s left to wait.",
+                Verdict::RateLimited {
+                    wait: Duration::from_secs(65),
+                },
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::AlreadySolved,
+            ),
+            ("Please log in.", Verdict::Unknown),
+        ];
+        for (response, verdict) in cases {
+            assert_eq!(Verdict::from_response(response), verdict);
+        }
+    }
+
+    #[test]
+    fn refuses_duplicates() {
+        let submissions = [
+            submission(Part::One, "42", Verdict::Incorrect),
+            submission(Part::Two, "43", Verdict::Incorrect),
+            submission(Part::One, "44", Verdict::Unknown),
+        ];
+        let check = |day, part, answer: &str| check(&submissions, day, part, &answer.into());
+
+        assert_eq!(
+            check(day!(1), Part::One, "42\n"),
+            Err(Refusal::Duplicate(Verdict::Incorrect))
+        );
+        assert_eq!(check(day!(1), Part::One, "43"), Ok(()));
+        assert_eq!(check(day!(1), Part::One, "44"), Ok(()));
+        assert_eq!(check(day!(2), Part::One, "42"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let submissions = [
+            submission(Part::One, "100", Verdict::TooHigh),
+            submission(Part::One, "10", Verdict::TooLow),
+        ];
+        let check = |part, answer: &str| check(&submissions, day!(1), part, &answer.into());
+
+        assert_eq!(
+            check(Part::One, "150"),
+            Err(Refusal::TooHigh(Answer::Integer(100)))
+        );
+        assert_eq!(
+            check(Part::One, "-5"),
+            Err(Refusal::TooLow(Answer::Integer(10)))
+        );
+        assert_eq!(
+            check(Part::One, "1000000000000000000000000000000000000000"),
+            Err(Refusal::TooHigh(Answer::Integer(100)))
+        );
+        assert_eq!(check(Part::One, "50"), Ok(()));
+        assert_eq!(check(Part::One, "abc"), Ok(()));
+        assert_eq!(check(Part::Two, "150"), Ok(()));
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submissions = vec![
+            submission(Part::One, "100", Verdict::TooHigh),
+            submission(Part::Two, "ABC", Verdict::Correct),
+        ];
+        let content = submissions
+            .iter()
+            .map(|x| serde_json::to_string(x).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(content.contains("\"part\":1,\"answer\":100,\"verdict\":\"too_high\""));
+        assert_eq!(jsonl::parse::<Submission>(&content).unwrap(), submissions);
+    }
+
+    #[test]
+    fn retries_rate_limited_submissions() {
+        let backend = FakeBackend::default();
+        backend.responses.borrow_mut().extend([
+            RATE_LIMITED.to_string(),
+            "That's the right answer!".to_string(),
+        ]);
+        let start = UNIX_EPOCH + Duration::from_secs(1_701_388_800);
+        let clock = FakeClock::new(start);
+        let mut waits = vec![];
+
+        let outcome = submit_with_retry(
+            &backend,
+            &clock,
+            Puzzle::new(2023, day!(1)),
+            Part::One,
+            &Answer::Integer(42),
+            || true,
+            |x| waits.push(x),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            outcome,
+            Outcome::Submitted {
+                verdict: Verdict::Correct,
+                ..
+            }
+        ));
+        assert_eq!(backend.submissions.borrow().len(), 2);
+        assert_eq!(clock.now(), start + Duration::from_secs(66));
+        assert_eq!(waits.first(), Some(&Duration::from_secs(66)));
+        assert_eq!(waits.last(), Some(&Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn stops_retrying_if_answer_changed() {
+        let backend = FakeBackend::default();
+        backend
+            .responses
+            .borrow_mut()
+            .push_back(RATE_LIMITED.into());
+        let clock = FakeClock::new(UNIX_EPOCH);
+
+        let outcome = submit_with_retry(
+            &backend,
+            &clock,
+            Puzzle::new(2023, day!(1)),
+            Part::One,
+            &Answer::Integer(42),
+            || false,
+            |_| {},
+        )
+        .unwrap();
+
+        assert_eq!(outcome, Outcome::Stopped);
+        assert_eq!(backend.submissions.borrow().len(), 1);
+    }
+}